@@ -1,8 +1,8 @@
 use super::{AssertionFailure, Spec};
 
 pub trait BooleanAssertions {
-    fn is_true(&mut self);
-    fn is_false(&mut self);
+    fn is_true(&mut self) -> &mut Self;
+    fn is_false(&mut self) -> &mut Self;
 }
 
 impl<'s> BooleanAssertions for Spec<'s, bool> {
@@ -11,13 +11,16 @@ impl<'s> BooleanAssertions for Spec<'s, bool> {
     /// ```rust,ignore
     /// assert_that(&true).is_true();
     /// ```
-    fn is_true(&mut self) {
-        if !*self.subject {
+    fn is_true(&mut self) -> &mut Self {
+        if !*self.subject() {
             AssertionFailure::from_spec(self)
+                .with_assertion_name("is_true")
                 .with_expected(format!("bool to be <true>"))
                 .with_actual(format!("<false>"))
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject is false. The subject type must be `bool`.
@@ -25,13 +28,16 @@ impl<'s> BooleanAssertions for Spec<'s, bool> {
     /// ```rust,ignore
     /// assert_that(&true).is_false();
     /// ```
-    fn is_false(&mut self) {
-        if *self.subject {
+    fn is_false(&mut self) -> &mut Self {
+        if *self.subject() {
             AssertionFailure::from_spec(self)
+                .with_assertion_name("is_false")
                 .with_expected(format!("bool to be <false>"))
                 .with_actual(format!("<true>"))
                 .fail();
         }
+
+        self
     }
 }
 
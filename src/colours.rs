@@ -0,0 +1,226 @@
+#[cfg(test)]
+use std::cell::Cell;
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const RED: &str = "\x1B[31m";
+const GREEN: &str = "\x1B[32m";
+const BOLD: &str = "\x1B[1m";
+const RESET: &str = "\x1B[0m";
+
+/// Controls whether failure messages are coloured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColourChoice {
+    /// Decide from the environment: `SPECTRAL_COLOUR`, `NO_COLOR`, `CLICOLOR_FORCE`, `CLICOLOR`
+    /// and whether stderr is a terminal, in that order of precedence.
+    Auto,
+    /// Always colour failure messages.
+    Always,
+    /// Never colour failure messages.
+    Never,
+}
+
+/// The choice made by `set_colour_choice`: 0 for `Auto`, 1 for `Always` and 2 for `Never`.
+#[cfg(not(test))]
+static COLOUR_CHOICE: AtomicUsize = AtomicUsize::new(0);
+
+// The tests run in parallel, so each of them makes its own choice, which defaults to never
+// colouring so that the panic messages can be asserted on.
+#[cfg(test)]
+thread_local! {
+    static COLOUR_CHOICE: Cell<usize> = const { Cell::new(2) };
+}
+
+/// The outcome of detecting colour support from the environment: 0 if it has not been detected
+/// yet, 1 if colours are supported and 2 if not.
+static DETECTED_COLOUR: AtomicUsize = AtomicUsize::new(0);
+
+/// Overrides whether failure messages are coloured, for example from a custom test harness.
+///
+/// Passing `ColourChoice::Auto` restores the default detection from the environment.
+pub fn set_colour_choice(choice: ColourChoice) {
+    let value = match choice {
+        ColourChoice::Auto => 0,
+        ColourChoice::Always => 1,
+        ColourChoice::Never => 2,
+    };
+
+    store_colour_choice(value);
+}
+
+fn colour_choice() -> ColourChoice {
+    match load_colour_choice() {
+        1 => ColourChoice::Always,
+        2 => ColourChoice::Never,
+        _ => ColourChoice::Auto,
+    }
+}
+
+#[cfg(not(test))]
+fn store_colour_choice(value: usize) {
+    COLOUR_CHOICE.store(value, Ordering::SeqCst);
+}
+
+#[cfg(not(test))]
+fn load_colour_choice() -> usize {
+    COLOUR_CHOICE.load(Ordering::SeqCst)
+}
+
+#[cfg(test)]
+fn store_colour_choice(value: usize) {
+    COLOUR_CHOICE.with(|choice| choice.set(value));
+}
+
+#[cfg(test)]
+fn load_colour_choice() -> usize {
+    COLOUR_CHOICE.with(Cell::get)
+}
+
+pub fn red() -> &'static str {
+    if enabled() { RED } else { "" }
+}
+
+pub fn green() -> &'static str {
+    if enabled() { GREEN } else { "" }
+}
+
+pub fn bold() -> &'static str {
+    if enabled() { BOLD } else { "" }
+}
+
+pub fn reset() -> &'static str {
+    if enabled() { RESET } else { "" }
+}
+
+fn enabled() -> bool {
+    match colour_choice() {
+        ColourChoice::Auto => detect_colour(),
+        choice => should_colour(choice, |_| None, false),
+    }
+}
+
+// The environment is only read for the first failure, as every message is built from several
+// colour lookups.
+fn detect_colour() -> bool {
+    match DETECTED_COLOUR.load(Ordering::Relaxed) {
+        1 => return true,
+        2 => return false,
+        _ => {}
+    }
+
+    let lookup = |name: &str| env::var_os(name).map(|value| value.to_string_lossy().into_owned());
+    let detected = should_colour(ColourChoice::Auto, lookup, io::stderr().is_terminal());
+
+    DETECTED_COLOUR.store(if detected { 1 } else { 2 }, Ordering::Relaxed);
+    detected
+}
+
+/// Decides whether to colour failure messages, given the choice made by `set_colour_choice`, a
+/// lookup of environment variables and whether stderr is a terminal.
+fn should_colour<E>(choice: ColourChoice, env: E, is_terminal: bool) -> bool
+    where E: Fn(&str) -> Option<String>
+{
+    match choice {
+        ColourChoice::Always => return true,
+        ColourChoice::Never => return false,
+        ColourChoice::Auto => {}
+    }
+
+    match env("SPECTRAL_COLOUR").or_else(|| env("SPECTRAL_COLOR")).as_deref() {
+        Some("always") => return true,
+        Some("never") => return false,
+        _ => {}
+    }
+
+    if env("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        return false;
+    }
+
+    if env("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0") {
+        return true;
+    }
+
+    if env("CLICOLOR").as_deref() == Some("0") {
+        return false;
+    }
+
+    is_terminal
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{bold, red, reset, set_colour_choice, should_colour, ColourChoice};
+
+    fn env<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|&&(key, _)| key == name)
+                .map(|&(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn should_colour_if_stderr_is_a_terminal() {
+        assert!(should_colour(ColourChoice::Auto, env(&[]), true));
+        assert!(!should_colour(ColourChoice::Auto, env(&[]), false));
+    }
+
+    #[test]
+    fn should_not_colour_if_no_color_is_set() {
+        assert!(!should_colour(ColourChoice::Auto, env(&[("NO_COLOR", "1")]), true));
+        assert!(!should_colour(ColourChoice::Auto,
+                               env(&[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")]),
+                               true));
+        assert!(should_colour(ColourChoice::Auto, env(&[("NO_COLOR", "")]), true));
+    }
+
+    #[test]
+    fn should_colour_if_clicolor_force_is_set() {
+        assert!(should_colour(ColourChoice::Auto, env(&[("CLICOLOR_FORCE", "1")]), false));
+        assert!(!should_colour(ColourChoice::Auto, env(&[("CLICOLOR_FORCE", "0")]), false));
+        assert!(should_colour(ColourChoice::Auto,
+                              env(&[("CLICOLOR_FORCE", "1"), ("CLICOLOR", "0")]),
+                              false));
+    }
+
+    #[test]
+    fn should_not_colour_if_clicolor_is_disabled() {
+        assert!(!should_colour(ColourChoice::Auto, env(&[("CLICOLOR", "0")]), true));
+        assert!(should_colour(ColourChoice::Auto, env(&[("CLICOLOR", "1")]), true));
+    }
+
+    #[test]
+    fn should_prefer_spectral_colour_over_other_variables() {
+        assert!(should_colour(ColourChoice::Auto,
+                              env(&[("SPECTRAL_COLOUR", "always"), ("NO_COLOR", "1")]),
+                              false));
+        assert!(!should_colour(ColourChoice::Auto,
+                               env(&[("SPECTRAL_COLOUR", "never"), ("CLICOLOR_FORCE", "1")]),
+                               true));
+        assert!(!should_colour(ColourChoice::Auto,
+                               env(&[("SPECTRAL_COLOUR", "auto"), ("NO_COLOR", "1")]),
+                               true));
+        assert!(should_colour(ColourChoice::Auto, env(&[("SPECTRAL_COLOR", "always")]), false));
+    }
+
+    #[test]
+    fn should_prefer_colour_choice_over_environment() {
+        assert!(should_colour(ColourChoice::Always,
+                              env(&[("SPECTRAL_COLOUR", "never"), ("NO_COLOR", "1")]),
+                              false));
+        assert!(!should_colour(ColourChoice::Never,
+                               env(&[("SPECTRAL_COLOUR", "always"), ("CLICOLOR_FORCE", "1")]),
+                               true));
+    }
+
+    #[test]
+    fn should_colour_as_set_by_colour_choice() {
+        set_colour_choice(ColourChoice::Always);
+        assert_eq!(format!("{}{}{}", red(), bold(), reset()), "\x1B[31m\x1B[1m\x1B[0m");
+
+        set_colour_choice(ColourChoice::Never);
+        assert_eq!(format!("{}{}{}", red(), bold(), reset()), "");
+    }
+}
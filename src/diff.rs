@@ -0,0 +1,574 @@
+use colours;
+
+use std::fmt::Debug;
+
+// Beyond this many lines in total, building the LCS table gets too expensive to be worth it.
+const MAX_DIFF_LINES: usize = 4000;
+
+// The number of unchanged lines shown around each change.
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, PartialEq)]
+enum Line<'a> {
+    Equal(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Describes how two values differ, to be added to the message of a failed equality assertion.
+///
+/// Strings spanning multiple lines are diffed line by line. Other values are pretty-printed using
+/// `{:#?}` and diffed, along with the path to the first field that differs.
+pub fn describe_difference<T: Debug + ?Sized>(expected: &T, actual: &T) -> Vec<String> {
+    if let Some(diff) = diff_debug_strings(&format!("{:?}", expected), &format!("{:?}", actual)) {
+        return vec![diff];
+    }
+
+    let expected_pretty = format!("{:#?}", expected);
+    let actual_pretty = format!("{:#?}", actual);
+
+    if !expected_pretty.contains('\n') && !actual_pretty.contains('\n') {
+        return vec![];
+    }
+
+    let expected_lines: Vec<&str> = expected_pretty.split('\n').collect();
+    let actual_lines: Vec<&str> = actual_pretty.split('\n').collect();
+
+    let lines = match diff_lines(&expected_lines, &actual_lines) {
+        Some(lines) => lines,
+        None => return vec![],
+    };
+
+    let mut description = vec![];
+
+    if let Some(path) = first_difference_path(&lines, &expected_lines, &actual_lines) {
+        description.push(format!("first difference at: {}", path));
+    }
+
+    description.push(render_diff(&lines));
+    description
+}
+
+/// Builds a line diff between two pieces of text, unless they are too long to be diffed.
+pub fn describe_text_difference(expected: &str, actual: &str) -> Option<String> {
+    let expected_lines: Vec<&str> = expected.split('\n').collect();
+    let actual_lines: Vec<&str> = actual.split('\n').collect();
+
+    diff_lines(&expected_lines, &actual_lines).map(|lines| render_diff(&lines))
+}
+
+/// Builds a line diff between the `Debug` representations of two values, if they are both
+/// strings and at least one of them spans multiple lines.
+fn diff_debug_strings(expected: &str, actual: &str) -> Option<String> {
+    let expected = parse_debug_string(expected)?;
+    let actual = parse_debug_string(actual)?;
+
+    if !expected.contains('\n') && !actual.contains('\n') {
+        return None;
+    }
+
+    describe_text_difference(&expected, &actual)
+}
+
+/// Renders a unified diff, with lines removed from the expected value marked with `-` and lines
+/// added in the actual value marked with `+`.
+fn render_diff(lines: &[Line]) -> String {
+    let mut rendered = vec!["diff (- expected, + actual):".to_string()];
+
+    for hunk in build_hunks(lines) {
+        rendered.push(render_hunk_header(lines, &hunk));
+        render_hunk(&lines[hunk.start..hunk.end], &mut rendered);
+    }
+
+    rendered.join("\n")
+}
+
+fn diff_lines<'a>(expected: &[&'a str], actual: &[&'a str]) -> Option<Vec<Line<'a>>> {
+    if expected.len() + actual.len() > MAX_DIFF_LINES {
+        return None;
+    }
+
+    let rows = expected.len() + 1;
+    let columns = actual.len() + 1;
+
+    // lengths[i * columns + j] is the length of the longest common subsequence of
+    // expected[i..] and actual[j..].
+    let mut lengths = vec![0usize; rows * columns];
+
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i * columns + j] = if expected[i] == actual[j] {
+                lengths[(i + 1) * columns + j + 1] + 1
+            } else {
+                ::std::cmp::max(lengths[(i + 1) * columns + j], lengths[i * columns + j + 1])
+            };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+
+    while i < expected.len() && j < actual.len() {
+        if expected[i] == actual[j] {
+            lines.push(Line::Equal(expected[i]));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * columns + j] >= lengths[i * columns + j + 1] {
+            lines.push(Line::Removed(expected[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(actual[j]));
+            j += 1;
+        }
+    }
+
+    lines.extend(expected[i..].iter().map(|line| Line::Removed(line)));
+    lines.extend(actual[j..].iter().map(|line| Line::Added(line)));
+
+    Some(lines)
+}
+
+struct Hunk {
+    start: usize,
+    end: usize,
+}
+
+fn build_hunks(lines: &[Line]) -> Vec<Hunk> {
+    let mut hunks: Vec<Hunk> = vec![];
+
+    for (index, line) in lines.iter().enumerate() {
+        if let Line::Equal(_) = *line {
+            continue;
+        }
+
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = ::std::cmp::min(index + CONTEXT_LINES + 1, lines.len());
+
+        match hunks.last_mut() {
+            Some(ref mut hunk) if hunk.end >= start => {
+                hunk.end = end;
+                continue;
+            }
+            _ => {}
+        }
+
+        hunks.push(Hunk { start, end });
+    }
+
+    hunks
+}
+
+fn render_hunk_header(lines: &[Line], hunk: &Hunk) -> String {
+    let count = |lines: &[Line], expected: bool| {
+        lines.iter()
+            .filter(|line| {
+                match **line {
+                    Line::Equal(_) => true,
+                    Line::Removed(_) => expected,
+                    Line::Added(_) => !expected,
+                }
+            })
+            .count()
+    };
+
+    let expected_start = count(&lines[..hunk.start], true) + 1;
+    let actual_start = count(&lines[..hunk.start], false) + 1;
+
+    format!("@@ -{},{} +{},{} @@",
+            expected_start,
+            count(&lines[hunk.start..hunk.end], true),
+            actual_start,
+            count(&lines[hunk.start..hunk.end], false))
+}
+
+fn render_hunk(lines: &[Line], rendered: &mut Vec<String>) {
+    let mut index = 0;
+
+    while index < lines.len() {
+        if let Line::Equal(line) = lines[index] {
+            rendered.push(format!("  {}", line));
+            index += 1;
+            continue;
+        }
+
+        let removed: Vec<&str> = lines[index..]
+            .iter()
+            .take_while(|line| matches!(**line, Line::Removed(_)))
+            .map(line_value)
+            .collect();
+        let added: Vec<&str> = lines[index + removed.len()..]
+            .iter()
+            .take_while(|line| matches!(**line, Line::Added(_)))
+            .map(line_value)
+            .collect();
+
+        index += removed.len() + added.len();
+
+        // Lines which were replaced one for one get their changed characters highlighted.
+        if removed.len() == added.len() {
+            for (removed_line, added_line) in removed.iter().zip(added.iter()) {
+                let (removed_highlighted, added_highlighted) =
+                    highlight_changes(removed_line, added_line);
+
+                rendered.push(format!("{}- {}{}",
+                                      colours::red(),
+                                      removed_highlighted,
+                                      colours::reset()));
+                rendered.push(format!("{}+ {}{}",
+                                      colours::green(),
+                                      added_highlighted,
+                                      colours::reset()));
+            }
+        } else {
+            for line in removed {
+                rendered.push(format!("{}- {}{}", colours::red(), line, colours::reset()));
+            }
+
+            for line in added {
+                rendered.push(format!("{}+ {}{}", colours::green(), line, colours::reset()));
+            }
+        }
+    }
+}
+
+fn line_value<'a>(line: &Line<'a>) -> &'a str {
+    match *line {
+        Line::Equal(value) | Line::Removed(value) | Line::Added(value) => value,
+    }
+}
+
+fn highlight_changes(removed: &str, added: &str) -> (String, String) {
+    let removed_chars: Vec<char> = removed.chars().collect();
+    let added_chars: Vec<char> = added.chars().collect();
+
+    let prefix = removed_chars.iter()
+        .zip(added_chars.iter())
+        .take_while(|&(a, b)| a == b)
+        .count();
+    let suffix = removed_chars[prefix..]
+        .iter()
+        .rev()
+        .zip(added_chars[prefix..].iter().rev())
+        .take_while(|&(a, b)| a == b)
+        .count();
+
+    (highlight_range(&removed_chars, prefix, removed_chars.len() - suffix, colours::red()),
+     highlight_range(&added_chars, prefix, added_chars.len() - suffix, colours::green()))
+}
+
+// Emboldens chars[start..end], restoring the colour of the rest of the line afterwards.
+fn highlight_range(chars: &[char], start: usize, end: usize, colour: &str) -> String {
+    let before: String = chars[..start].iter().cloned().collect();
+    let changed: String = chars[start..end].iter().cloned().collect();
+    let after: String = chars[end..].iter().cloned().collect();
+
+    if changed.is_empty() {
+        return format!("{}{}", before, after);
+    }
+
+    format!("{}{}{}{}{}{}", before, colours::bold(), changed, colours::reset(), colour, after)
+}
+
+fn first_difference_path(lines: &[Line],
+                         expected_lines: &[&str],
+                         actual_lines: &[&str])
+                         -> Option<String> {
+    let (mut expected_index, mut actual_index) = (0, 0);
+
+    let path = lines.iter()
+        .filter_map(|line| {
+            match *line {
+                Line::Equal(_) => {
+                    expected_index += 1;
+                    actual_index += 1;
+                    None
+                }
+                Line::Removed(_) => Some(debug_path(expected_lines, expected_index)),
+                Line::Added(_) => Some(debug_path(actual_lines, actual_index)),
+            }
+        })
+        .next();
+
+    path.and_then(|path| if path.is_empty() { None } else { Some(path) })
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Container {
+    Struct,
+    Tuple,
+    Sequence,
+    Map,
+}
+
+struct OpenContainer {
+    container: Container,
+    path: String,
+    items: usize,
+}
+
+/// Works out the path (such as `.user.address[0].zip`) to the value on the given line of a
+/// pretty-printed `Debug` representation, by following its indentation.
+fn debug_path(lines: &[&str], target: usize) -> String {
+    let mut open: Vec<OpenContainer> = vec![];
+
+    for (index, line) in lines.iter().enumerate() {
+        let depth = (line.len() - line.trim_start().len()) / 4;
+        let content = line.trim().trim_end_matches(',');
+
+        if content.starts_with('}') || content.starts_with(']') || content.starts_with(')') {
+            let path = open.get(depth).map(|container| container.path.clone());
+            open.truncate(depth);
+
+            if index == target {
+                return path.unwrap_or_default();
+            }
+
+            continue;
+        }
+
+        open.truncate(depth + 1);
+
+        let (path, value) = match depth.checked_sub(1).and_then(|parent| open.get_mut(parent)) {
+            Some(parent) => {
+                let (key, value) = item_key(parent, content);
+                (format!("{}{}", parent.path, key), value)
+            }
+            None => (String::new(), content),
+        };
+
+        if index == target {
+            return path;
+        }
+
+        let container = if value == "{" {
+            Some(Container::Map)
+        } else if value.ends_with('{') {
+            Some(Container::Struct)
+        } else if value.ends_with('[') {
+            Some(Container::Sequence)
+        } else if value.ends_with('(') {
+            Some(Container::Tuple)
+        } else {
+            None
+        };
+
+        open.truncate(depth);
+
+        if let Some(container) = container {
+            open.push(OpenContainer {
+                container,
+                path,
+                items: 0,
+            });
+        }
+    }
+
+    String::new()
+}
+
+fn item_key<'a>(parent: &mut OpenContainer, content: &'a str) -> (String, &'a str) {
+    let index = parent.items;
+    parent.items += 1;
+
+    match (parent.container, split_key(content)) {
+        (Container::Struct, Some((field, value))) => (format!(".{}", field), value),
+        (Container::Map, Some((key, value))) => (format!("[{}]", key), value),
+        (Container::Tuple, _) => (format!(".{}", index), content),
+        _ => (format!("[{}]", index), content),
+    }
+}
+
+// Splits `key: value` on the first separator which is not inside a quoted key.
+fn split_key(content: &str) -> Option<(&str, &str)> {
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for (index, c) in content.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ':' if !quoted && content[index..].starts_with(": ") => {
+                return Some((&content[..index], &content[index + 2..]));
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Reverses the escaping done by the `Debug` implementation of `str`, returning `None` if the
+/// value is not a quoted string.
+fn parse_debug_string(value: &str) -> Option<String> {
+    if value.len() < 2 || !value.starts_with('"') || !value.ends_with('"') {
+        return None;
+    }
+
+    let mut parsed = String::new();
+    let mut chars = value[1..value.len() - 1].chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return None,
+            '\\' => {
+                let escaped = match chars.next() {
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('0') => '\0',
+                    Some('\\') => '\\',
+                    Some('"') => '"',
+                    Some('\'') => '\'',
+                    Some('u') => {
+                        if chars.next() != Some('{') {
+                            return None;
+                        }
+
+                        let hex: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                        u32::from_str_radix(&hex, 16).ok().and_then(::std::char::from_u32)?
+                    }
+                    _ => return None,
+                };
+
+                parsed.push(escaped);
+            }
+            c => parsed.push(c),
+        }
+    }
+
+    Some(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{describe_difference, diff_debug_strings, parse_debug_string};
+    use super::super::colours::{set_colour_choice, ColourChoice};
+
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn should_parse_escaped_debug_strings() {
+        let value = "tab\there\nquote \" back\\slash \u{301}";
+        let debug = format!("{:?}", value);
+
+        assert_eq!(parse_debug_string(&debug), Some(value.to_string()));
+    }
+
+    #[test]
+    fn should_not_parse_values_which_are_not_debug_strings() {
+        assert_eq!(parse_debug_string("Some(\"value\")"), None);
+        assert_eq!(parse_debug_string("[\"a\", \"b\"]"), None);
+        assert_eq!(parse_debug_string("1"), None);
+    }
+
+    #[test]
+    fn should_not_diff_single_line_strings() {
+        assert_eq!(diff_debug_strings("\"Hello\"", "\"Hi\""), None);
+    }
+
+    #[test]
+    fn should_diff_multi_line_strings_by_line() {
+        let expected = format!("{:?}", "one\ntwo\nthree\nfour");
+        let actual = format!("{:?}", "one\n2\nthree\nfour\nfive");
+
+        assert_eq!(diff_debug_strings(&expected, &actual),
+                   Some("diff (- expected, + actual):\n\
+                         @@ -1,4 +1,5 @@\n  one\n- two\n+ 2\n  three\n  four\n+ five"
+                       .to_string()));
+    }
+
+    #[test]
+    fn should_colour_changed_lines_only_if_colours_are_enabled() {
+        let expected = format!("{:?}", "one\ntwo");
+        let actual = format!("{:?}", "one\nto");
+
+        set_colour_choice(ColourChoice::Always);
+        let coloured = diff_debug_strings(&expected, &actual);
+        set_colour_choice(ColourChoice::Never);
+
+        assert_eq!(coloured,
+                   Some("diff (- expected, + actual):\n@@ -1,2 +1,2 @@\n  one\n\
+                         \x1B[31m- t\x1B[1mw\x1B[0m\x1B[31mo\x1B[0m\n\x1B[32m+ to\x1B[0m"
+                       .to_string()));
+        assert_eq!(diff_debug_strings(&expected, &actual),
+                   Some("diff (- expected, + actual):\n@@ -1,2 +1,2 @@\n  one\n- two\n+ to"
+                       .to_string()));
+    }
+
+    #[test]
+    fn should_only_show_context_around_changes() {
+        let expected = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12";
+        let actual = "1\nB\n3\n4\n5\n6\n7\n8\n9\n10\nK\n12";
+
+        assert_eq!(diff_debug_strings(&format!("{:?}", expected), &format!("{:?}", actual)),
+                   Some("diff (- expected, + actual):\n\
+                         @@ -1,5 +1,5 @@\n  1\n- 2\n+ B\n  3\n  4\n  5\n\
+                         @@ -8,5 +8,5 @@\n  8\n  9\n  10\n- 11\n+ K\n  12"
+                       .to_string()));
+    }
+
+    #[test]
+    fn should_not_describe_difference_of_single_line_values() {
+        assert_eq!(describe_difference(&1, &2), Vec::<String>::new());
+        assert_eq!(describe_difference(&"Hello", &"Hi"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn should_describe_difference_of_nested_values_with_path() {
+        let expected = User {
+            name: "bob".to_string(),
+            address: Address {
+                lines: vec!["1 Main Street".to_string()],
+                zip: "12345".to_string(),
+            },
+        };
+        let actual = User {
+            name: "bob".to_string(),
+            address: Address {
+                lines: vec!["1 Main Street".to_string()],
+                zip: "54321".to_string(),
+            },
+        };
+
+        assert_eq!(describe_difference(&expected, &actual),
+                   vec!["first difference at: .address.zip".to_string(),
+                        "diff (- expected, + actual):\n\
+                         @@ -4,6 +4,6 @@\n\
+                         \x20\x20        lines: [\n\
+                         \x20\x20            \"1 Main Street\",\n\
+                         \x20\x20        ],\n\
+                         -         zip: \"12345\",\n\
+                         +         zip: \"54321\",\n\
+                         \x20\x20    },\n\
+                         \x20\x20}"
+                            .to_string()]);
+    }
+
+    #[test]
+    fn should_describe_path_into_sequences_tuples_and_maps() {
+        let mut expected = BTreeMap::new();
+        expected.insert("key", vec![(1, 2)]);
+        let mut actual = BTreeMap::new();
+        actual.insert("key", vec![(1, 3)]);
+
+        assert_eq!(describe_difference(&expected, &actual)[0],
+                   "first difference at: [\"key\"][0].1");
+    }
+
+    // The fields are only read through `Debug`.
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct User {
+        name: String,
+        address: Address,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Address {
+        lines: Vec<String>,
+        zip: String,
+    }
+}
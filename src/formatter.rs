@@ -0,0 +1,325 @@
+//! Formatters controlling how failing assertions are rendered.
+//!
+//! By default, a failing assertion panics with the same message as the `Display` implementation
+//! of `AssertionError`. A different `FailureFormatter` can be installed for the whole process with
+//! `set_formatter`, or for the current thread only with `set_thread_formatter`. A formatter set
+//! for the thread takes precedence over the global one.
+//!
+//! ```rust,ignore
+//! spectral::formatter::set_formatter(GitHubActionsFormatter);
+//! ```
+
+use std::cell::RefCell;
+use std::sync::{Arc, RwLock};
+
+use super::AssertionError;
+
+/// Renders a failed assertion into the message that is used to panic.
+pub trait FailureFormatter: Send + Sync {
+    fn format(&self, error: &AssertionError) -> String;
+}
+
+/// Renders failures in the standard multi-line layout, as provided by `AssertionError`'s
+/// `Display` implementation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultFormatter;
+
+/// Renders failures on a single line, without colours or any additional context such as diffs.
+///
+/// ```text
+/// [tests/parser.rs:112] for subject [count]: expected: <2> but was: <1>
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompactFormatter;
+
+/// Renders failures as GitHub Actions workflow commands, so that they are shown as annotations
+/// against the line of the failing assertion.
+///
+/// ```text
+/// ::error file=tests/parser.rs,line=112::expected: <2> but was: <1>
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GitHubActionsFormatter;
+
+/// Renders failures as plain text without colours or indentation, suitable for the failure
+/// message of a JUnit report.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JUnitFormatter;
+
+static GLOBAL_FORMATTER: RwLock<Option<Arc<dyn FailureFormatter>>> = RwLock::new(None);
+
+thread_local! {
+    static THREAD_FORMATTER: RefCell<Option<Arc<dyn FailureFormatter>>> = RefCell::new(None);
+}
+
+/// Installs a formatter for failures raised on any thread.
+pub fn set_formatter<F: FailureFormatter + 'static>(formatter: F) {
+    *GLOBAL_FORMATTER.write().unwrap_or_else(|poisoned| poisoned.into_inner()) =
+        Some(Arc::new(formatter));
+}
+
+/// Restores the default formatter for failures raised on any thread.
+pub fn reset_formatter() {
+    *GLOBAL_FORMATTER.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+/// Installs a formatter for failures raised on the current thread, taking precedence over the
+/// global formatter.
+pub fn set_thread_formatter<F: FailureFormatter + 'static>(formatter: F) {
+    THREAD_FORMATTER.with(|current| *current.borrow_mut() = Some(Arc::new(formatter)));
+}
+
+/// Removes the formatter installed for the current thread.
+pub fn reset_thread_formatter() {
+    THREAD_FORMATTER.with(|current| *current.borrow_mut() = None);
+}
+
+/// Renders the failure using the formatter installed for the current thread, the global
+/// formatter, or the default formatter, in that order.
+pub(crate) fn format_failure(error: &AssertionError) -> String {
+    if let Some(formatter) = THREAD_FORMATTER.with(|current| current.borrow().clone()) {
+        return formatter.format(error);
+    }
+
+    let global = GLOBAL_FORMATTER.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+
+    match global {
+        Some(formatter) => formatter.format(error),
+        None => DefaultFormatter.format(error),
+    }
+}
+
+impl FailureFormatter for DefaultFormatter {
+    fn format(&self, error: &AssertionError) -> String {
+        error.to_string()
+    }
+}
+
+impl FailureFormatter for CompactFormatter {
+    fn format(&self, error: &AssertionError) -> String {
+        let mut parts = vec![];
+
+        if let Some(ref location) = error.location {
+            parts.push(format!("[{}]", location));
+        }
+
+        parts.push(build_summary(error));
+
+        parts.join(" ")
+    }
+}
+
+impl FailureFormatter for GitHubActionsFormatter {
+    fn format(&self, error: &AssertionError) -> String {
+        let mut properties = vec![];
+
+        if let Some(ref location) = error.location {
+            match location.rfind(':') {
+                Some(index) if location[index + 1..].parse::<u32>().is_ok() => {
+                    properties.push(format!("file={}", escape_property(&location[..index])));
+                    properties.push(format!("line={}", &location[index + 1..]));
+                }
+                _ => properties.push(format!("file={}", escape_property(location))),
+            }
+        }
+
+        if let Some(assertion_name) = error.assertion_name {
+            properties.push(format!("title={}", escape_property(assertion_name)));
+        }
+
+        let mut message = build_summary(error);
+
+        for context in &error.context {
+            message.push_str("\n\n");
+            message.push_str(&strip_colours(context));
+        }
+
+        if properties.is_empty() {
+            format!("::error::{}", escape_data(&message))
+        } else {
+            format!("::error {}::{}", properties.join(","), escape_data(&message))
+        }
+    }
+}
+
+impl FailureFormatter for JUnitFormatter {
+    fn format(&self, error: &AssertionError) -> String {
+        let mut lines = vec![];
+
+        if let Some(assertion_name) = error.assertion_name {
+            lines.push(format!("assertion failed: {}", assertion_name));
+        }
+
+        if let Some(ref description) = error.description {
+            lines.push(format!("description: {}", description));
+        }
+
+        if let Some(ref subject_name) = error.subject_name {
+            lines.push(format!("subject: {}", subject_name));
+        }
+
+        match error.message {
+            Some(ref message) => lines.push(message.clone()),
+            None => {
+                lines.push(format!("expected: {}", error.expected.clone().unwrap_or_default()));
+                lines.push(format!(" but was: {}", error.actual.clone().unwrap_or_default()));
+            }
+        }
+
+        for context in &error.context {
+            lines.push(String::new());
+            lines.push(strip_colours(context));
+        }
+
+        if let Some(ref location) = error.location {
+            lines.push(String::new());
+            lines.push(format!("at location: {}", location));
+        }
+
+        lines.join("\n")
+    }
+}
+
+fn build_summary(error: &AssertionError) -> String {
+    let mut summary = String::new();
+
+    if let Some(ref description) = error.description {
+        summary.push_str(&format!("{}: ", description));
+    }
+
+    if let Some(ref subject_name) = error.subject_name {
+        summary.push_str(&format!("for subject [{}]: ", subject_name));
+    }
+
+    match error.message {
+        Some(ref message) => summary.push_str(message),
+        None => {
+            summary.push_str(&format!("expected: {} but was: {}",
+                                      error.expected.clone().unwrap_or_default(),
+                                      error.actual.clone().unwrap_or_default()))
+        }
+    }
+
+    summary
+}
+
+fn strip_colours(value: &str) -> String {
+    let mut stripped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1B' {
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
+
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+    use super::super::AssertionError;
+    use super::*;
+
+    fn failure() -> AssertionError {
+        AssertionError {
+            assertion_name: Some("is_equal_to"),
+            expected: Some("<2>".to_string()),
+            actual: Some("<1>".to_string()),
+            message: None,
+            description: None,
+            subject_name: Some("count".to_string()),
+            location: Some("tests/parser.rs:112".to_string()),
+            context: vec![],
+        }
+    }
+
+    #[test]
+    fn default_formatter_should_match_display() {
+        let error = failure();
+
+        assert_eq!(DefaultFormatter.format(&error), error.to_string());
+    }
+
+    #[test]
+    fn compact_formatter_should_render_a_single_line() {
+        let mut error = failure();
+        error.context.push("diff (- expected, + actual):\n- 2\n+ 1".to_string());
+
+        assert_eq!(CompactFormatter.format(&error),
+                   "[tests/parser.rs:112] for subject [count]: expected: <2> but was: <1>");
+    }
+
+    #[test]
+    fn compact_formatter_should_render_message() {
+        let mut error = failure();
+        error.message = Some("expected value to be present".to_string());
+        error.subject_name = None;
+        error.location = None;
+
+        assert_eq!(CompactFormatter.format(&error), "expected value to be present");
+    }
+
+    #[test]
+    fn github_actions_formatter_should_render_annotation() {
+        let mut error = failure();
+        error.context.push("\x1B[31m- 2\x1B[0m\n\x1B[32m+ 1\x1B[0m".to_string());
+
+        assert_eq!(GitHubActionsFormatter.format(&error),
+                   "::error file=tests/parser.rs,line=112,title=is_equal_to::for subject \
+                    [count]: expected: <2> but was: <1>%0A%0A- 2%0A+ 1");
+    }
+
+    #[test]
+    fn github_actions_formatter_should_render_annotation_without_location() {
+        let mut error = failure();
+        error.assertion_name = None;
+        error.location = None;
+
+        assert_eq!(GitHubActionsFormatter.format(&error),
+                   "::error::for subject [count]: expected: <2> but was: <1>");
+    }
+
+    #[test]
+    fn junit_formatter_should_render_plain_text() {
+        let mut error = failure();
+        error.context.push("first difference at: .value".to_string());
+
+        assert_eq!(JUnitFormatter.format(&error),
+                   "assertion failed: is_equal_to\nsubject: count\nexpected: <2>\n but was: \
+                    <1>\n\nfirst difference at: .value\n\nat location: tests/parser.rs:112");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected: <2> but was: <1>")]
+    fn should_panic_with_thread_formatter() {
+        set_thread_formatter(CompactFormatter);
+        assert_that(&1).is_equal_to(2);
+    }
+
+    #[test]
+    fn should_restore_default_formatter() {
+        set_thread_formatter(CompactFormatter);
+        reset_thread_formatter();
+
+        let error = failure();
+
+        assert_eq!(format_failure(&error), error.to_string());
+    }
+}
@@ -1,27 +1,135 @@
 use super::{AssertionFailure, Spec};
 
 use std::borrow::Borrow;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
-use std::hash::Hash;
-
-pub trait HashMapAssertions<'s, K: Hash + Eq, V: PartialEq> {
-    fn has_length(&mut self, expected: usize);
-    fn is_empty(&mut self);
-    fn contains_key<E: Borrow<K>>(&mut self, expected_key: E) -> Spec<'s, V>;
-    fn does_not_contain_key<E: Borrow<K>>(&mut self, expected_key: E);
-    fn contains_entry<E: Borrow<K>, F: Borrow<V>>(&mut self, expected_key: E, expected_value: F);
-    fn does_not_contain_entry<E: Borrow<K>, F: Borrow<V>>(&mut self,
-                                                          expected_key: E,
-                                                          expected_value: F);
+use std::hash::{BuildHasher, Hash};
+
+/// A collection of values looked up by key, such as a `HashMap` with any hasher or a
+/// `BTreeMap`.
+///
+/// This is what `MapAssertions` are implemented against, along with `MapGet` for looking up its
+/// keys, and can be implemented for other map types to make the assertions available for them.
+pub trait Map {
+    type Key;
+    type Value;
+
+    /// The name of the map type, used in failure messages.
+    const NAME: &'static str;
+
+    fn map_len(&self) -> usize;
+    fn map_keys(&self) -> Vec<&Self::Key>;
 }
 
-impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
-    where K: Hash + Eq + Debug,
-          V: PartialEq + Debug
+/// Looks up the values of a `Map` by `Q`, which is either the key type or a borrowed form of it,
+/// such as `str` for `String` keys.
+///
+/// This is separate from `Map` as each map type places its own bounds on `Q`, such as `Hash` and
+/// `Eq` for `HashMap` or `Ord` for `BTreeMap`.
+pub trait MapGet<Q: ?Sized>: Map
+    where Self::Key: Borrow<Q>
 {
-    /// Asserts that the length of the subject hashmap is equal to the provided length. The subject
-    /// type must be of `HashMap`.
+    fn map_get(&self, key: &Q) -> Option<&Self::Value>;
+}
+
+/// Assertions against a `Map`, whose keys are given as `&Q` for any `Q` the map can be looked up
+/// by, such as `&str` for a map with `String` keys.
+pub trait MapAssertions<'s, K, V> {
+    /// The type of the subject map.
+    type Map: Map<Key = K, Value = V>;
+
+    fn has_length(&mut self, expected: usize) -> &mut Self;
+    fn is_empty(&mut self) -> &mut Self;
+    fn contains_key<Q: ?Sized + Debug>(&mut self, expected_key: &Q) -> Spec<'_, V>
+        where K: Borrow<Q>,
+              Self::Map: MapGet<Q>;
+    fn does_not_contain_key<Q: ?Sized + Debug>(&mut self, expected_key: &Q) -> &mut Self
+        where K: Borrow<Q>,
+              Self::Map: MapGet<Q>;
+    fn contains_entry<Q: ?Sized + Debug, F: Borrow<V>>(&mut self,
+                                                       expected_key: &Q,
+                                                       expected_value: F)
+                                                       -> &mut Self
+        where K: Borrow<Q>,
+              Self::Map: MapGet<Q>;
+    fn does_not_contain_entry<Q: ?Sized + Debug, F: Borrow<V>>(&mut self,
+                                                               expected_key: &Q,
+                                                               expected_value: F)
+                                                               -> &mut Self
+        where K: Borrow<Q>,
+              Self::Map: MapGet<Q>;
+}
+
+/// The assertions of `MapAssertions`, under the name they had when they were only available for
+/// `HashMap`.
+///
+/// This is implemented for everything which implements `MapAssertions`, and both are exported by
+/// the prelude.
+pub trait HashMapAssertions<'s, K, V>: MapAssertions<'s, K, V> {}
+
+impl<'s, K, V, A> HashMapAssertions<'s, K, V> for A where A: MapAssertions<'s, K, V> + ?Sized {}
+
+impl<K, V, S> Map for HashMap<K, V, S>
+    where K: Hash + Eq,
+          S: BuildHasher
+{
+    type Key = K;
+    type Value = V;
+    const NAME: &'static str = "hashmap";
+
+    fn map_len(&self) -> usize {
+        self.len()
+    }
+
+    fn map_keys(&self) -> Vec<&K> {
+        self.keys().collect()
+    }
+}
+
+impl<K, V, S, Q: ?Sized> MapGet<Q> for HashMap<K, V, S>
+    where K: Borrow<Q> + Hash + Eq,
+          Q: Hash + Eq,
+          S: BuildHasher
+{
+    fn map_get(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<K, V> Map for BTreeMap<K, V>
+    where K: Ord
+{
+    type Key = K;
+    type Value = V;
+    const NAME: &'static str = "btreemap";
+
+    fn map_len(&self) -> usize {
+        self.len()
+    }
+
+    fn map_keys(&self) -> Vec<&K> {
+        self.keys().collect()
+    }
+}
+
+impl<K, V, Q: ?Sized> MapGet<Q> for BTreeMap<K, V>
+    where K: Borrow<Q> + Ord,
+          Q: Ord
+{
+    fn map_get(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<'s, M> MapAssertions<'s, M::Key, M::Value> for Spec<'s, M>
+    where M: Map,
+          M::Key: Debug,
+          M::Value: PartialEq + Debug
+{
+    type Map = M;
+
+    /// Asserts that the length of the subject map is equal to the provided length. The subject
+    /// type must be a `Map`, such as a `HashMap` or `BTreeMap`.
     ///
     /// ```rust,ignore
     /// let mut test_map = HashMap::new();
@@ -30,36 +138,43 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
     ///
     /// assert_that(&test_map).has_length(2);
     /// ```
-    fn has_length(&mut self, expected: usize) {
-        let subject = self.subject;
+    fn has_length(&mut self, expected: usize) -> &mut Self {
+        let length = self.subject().map_len();
 
-        if subject.len() != expected {
+        if length != expected {
             AssertionFailure::from_spec(self)
-                .with_expected(format!("hashmap to have length <{}>", expected))
-                .with_actual(format!("<{}>", subject.len()))
+                .with_assertion_name("has_length")
+                .with_expected(format!("{} to have length <{}>", M::NAME, expected))
+                .with_actual(format!("<{}>", length))
                 .fail();
         }
+
+        self
     }
 
-    /// Asserts that the subject hashmap is empty. The subject type must be of `HashMap`.
+    /// Asserts that the subject map is empty. The subject type must be a `Map`, such as a
+    /// `HashMap` or `BTreeMap`.
     ///
     /// ```rust,ignore
     /// let test_map: HashMap<u8, u8> = HashMap::new();
     /// assert_that(&test_map).is_empty();
     /// ```
-    fn is_empty(&mut self) {
-        let subject = self.subject;
+    fn is_empty(&mut self) -> &mut Self {
+        let length = self.subject().map_len();
 
-        if !subject.is_empty() {
+        if length != 0 {
             AssertionFailure::from_spec(self)
-                .with_expected(format!("an empty hashmap"))
-                .with_actual(format!("a hashmap with length <{:?}>", subject.len()))
+                .with_assertion_name("is_empty")
+                .with_expected(format!("an empty {}", M::NAME))
+                .with_actual(format!("a {} with length <{:?}>", M::NAME, length))
                 .fail();
         }
+
+        self
     }
 
-    /// Asserts that the subject hashmap contains the expected key. The subject type must be
-    /// of `HashMap`.
+    /// Asserts that the subject map contains the expected key. The subject type must be a
+    /// `Map`, such as a `HashMap` or `BTreeMap`.
     ///
     /// This will return a new `Spec` containing the associated value if the key is present.
     ///
@@ -69,31 +184,25 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
     ///
     /// assert_that(&test_map).contains_key(&"hello");
     /// ```
-    fn contains_key<E: Borrow<K>>(&mut self, expected_key: E) -> Spec<'s, V> {
-        let subject = self.subject;
-        let borrowed_expected_key = expected_key.borrow();
-
-        if let Some(value) = subject.get(borrowed_expected_key) {
-            return Spec {
-                subject: value,
-                subject_name: self.subject_name,
-                location: self.location.clone(),
-                description: self.description,
-            };
+    fn contains_key<Q: ?Sized + Debug>(&mut self, expected_key: &Q) -> Spec<'_, M::Value>
+        where M::Key: Borrow<Q>,
+              M: MapGet<Q>
+    {
+        let subject = self.subject();
+
+        if let Some(value) = subject.map_get(expected_key) {
+            return self.derive(value);
         }
 
-        let subject_keys: Vec<&K> = subject.keys().collect();
-
         AssertionFailure::from_spec(self)
-            .with_expected(format!("hashmap to contain key <{:?}>", borrowed_expected_key))
-            .with_actual(format!("<{:?}>", subject_keys))
-            .fail();
-
-        unreachable!();
+            .with_assertion_name("contains_key")
+            .with_expected(format!("{} to contain key <{:?}>", M::NAME, expected_key))
+            .with_actual(format!("<{:?}>", subject.map_keys()))
+            .fail_fatal();
     }
 
-    /// Asserts that the subject hashmap does not contain the provided key. The subject type must be
-    /// of `HashMap`.
+    /// Asserts that the subject map does not contain the provided key. The subject type must be
+    /// a `Map`, such as a `HashMap` or `BTreeMap`.
     ///
     /// ```rust,ignore
     /// let mut test_map = HashMap::new();
@@ -101,20 +210,27 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
     ///
     /// assert_that(&test_map).does_not_contain_key(&"hey");
     /// ```
-    fn does_not_contain_key<E: Borrow<K>>(&mut self, expected_key: E) {
-        let subject = self.subject;
-        let borrowed_expected_key = expected_key.borrow();
+    fn does_not_contain_key<Q: ?Sized + Debug>(&mut self, expected_key: &Q) -> &mut Self
+        where M::Key: Borrow<Q>,
+              M: MapGet<Q>
+    {
+        let subject = self.subject();
 
-        if subject.get(borrowed_expected_key).is_some() {
+        if subject.map_get(expected_key).is_some() {
             AssertionFailure::from_spec(self)
-                .with_expected(format!("hashmap to not contain key <{:?}>", borrowed_expected_key))
-                .with_actual(format!("present in hashmap"))
+                .with_assertion_name("does_not_contain_key")
+                .with_expected(format!("{} to not contain key <{:?}>",
+                                       M::NAME,
+                                       expected_key))
+                .with_actual(format!("present in {}", M::NAME))
                 .fail();
         }
+
+        self
     }
 
-    /// Asserts that the subject hashmap contains the expected key with the expected value.
-    /// The subject type must be of `HashMap`.
+    /// Asserts that the subject map contains the expected key with the expected value.
+    /// The subject type must be a `Map`, such as a `HashMap` or `BTreeMap`.
     ///
     /// ```rust,ignore
     /// let mut test_map = HashMap::new();
@@ -122,41 +238,48 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
     ///
     /// assert_that(&test_map).contains_entry(&"hello", &"hi");
     /// ```
-    fn contains_entry<E: Borrow<K>, F: Borrow<V>>(&mut self, expected_key: E, expected_value: F) {
-        let subject = self.subject;
-        let borrowed_expected_key = expected_key.borrow();
+    fn contains_entry<Q: ?Sized + Debug, F: Borrow<M::Value>>(&mut self,
+                                                              expected_key: &Q,
+                                                              expected_value: F)
+                                                              -> &mut Self
+        where M::Key: Borrow<Q>,
+              M: MapGet<Q>
+    {
+        let subject = self.subject();
         let borrowed_expected_value = expected_value.borrow();
 
-        let expected_message = format!("hashmap containing key <{:?}> with value <{:?}>",
-                                       borrowed_expected_key,
+        let expected_message = format!("{} containing key <{:?}> with value <{:?}>",
+                                       M::NAME,
+                                       expected_key,
                                        borrowed_expected_value);
 
-        if let Some(value) = subject.get(borrowed_expected_key) {
+        if let Some(value) = subject.map_get(expected_key) {
             if value.eq(borrowed_expected_value) {
-                return;
+                return self;
             }
 
             AssertionFailure::from_spec(self)
+                .with_assertion_name("contains_entry")
                 .with_expected(expected_message)
                 .with_actual(format!("key <{:?}> with value <{:?}> instead",
-                                     borrowed_expected_key,
+                                     expected_key,
                                      value))
                 .fail();
 
-            unreachable!();
+            return self;
         }
 
-        let subject_keys: Vec<&K> = subject.keys().collect();
-
         AssertionFailure::from_spec(self)
+            .with_assertion_name("contains_entry")
             .with_expected(expected_message)
-            .with_actual(format!("no matching key, keys are <{:?}>", subject_keys))
+            .with_actual(format!("no matching key, keys are <{:?}>", subject.map_keys()))
             .fail();
 
+        self
     }
 
-    /// Asserts that the subject hashmap does not contains the provided key and value.
-    /// The subject type must be of `HashMap`.
+    /// Asserts that the subject map does not contains the provided key and value.
+    /// The subject type must be a `Map`, such as a `HashMap` or `BTreeMap`.
     ///
     /// ```rust,ignore
     /// let mut test_map = HashMap::new();
@@ -164,25 +287,32 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
     ///
     /// assert_that(&test_map).does_not_contain_entry(&"hello", &"hey");
     /// ```
-    fn does_not_contain_entry<E: Borrow<K>, F: Borrow<V>>(&mut self,
-                                                          expected_key: E,
-                                                          expected_value: F) {
-        let subject = self.subject;
-        let borrowed_expected_key = expected_key.borrow();
+    fn does_not_contain_entry<Q: ?Sized + Debug, F: Borrow<M::Value>>(&mut self,
+                                                                      expected_key: &Q,
+                                                                      expected_value: F)
+                                                                      -> &mut Self
+        where M::Key: Borrow<Q>,
+              M: MapGet<Q>
+    {
+        let subject = self.subject();
         let borrowed_expected_value = expected_value.borrow();
 
-        if let Some(value) = subject.get(borrowed_expected_key) {
+        if let Some(value) = subject.map_get(expected_key) {
             if !value.eq(borrowed_expected_value) {
-                return;
+                return self;
             }
 
             AssertionFailure::from_spec(self)
-                .with_expected(format!("hashmap to not contain key <{:?}> with value <{:?}>",
-                                       borrowed_expected_key,
+                .with_assertion_name("does_not_contain_entry")
+                .with_expected(format!("{} to not contain key <{:?}> with value <{:?}>",
+                                       M::NAME,
+                                       expected_key,
                                        borrowed_expected_value))
-                .with_actual(format!("present in hashmap"))
+                .with_actual(format!("present in {}", M::NAME))
                 .fail();
         }
+
+        self
     }
 }
 
@@ -191,7 +321,8 @@ mod tests {
 
     use super::super::prelude::*;
 
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
+    use std::collections::hash_map::RandomState;
 
     #[test]
     fn should_not_panic_if_hashmap_length_matches_expected() {
@@ -234,7 +365,6 @@ mod tests {
         test_map.insert("hello", "hi");
 
         assert_that(&test_map).contains_key("hello");
-        assert_that(&test_map).contains_key(&mut "hello");
         assert_that(&test_map).contains_key(&"hello");
     }
 
@@ -272,7 +402,6 @@ mod tests {
         test_map.insert("hello", "hi");
 
         assert_that(&test_map).does_not_contain_key("hey");
-        assert_that(&test_map).does_not_contain_key(&mut "hey");
         assert_that(&test_map).does_not_contain_key(&"hey");
     }
 
@@ -300,7 +429,6 @@ mod tests {
         test_map.insert("hello", "hi");
 
         assert_that(&test_map).contains_entry("hello", "hi");
-        assert_that(&test_map).contains_entry(&mut "hello", &mut "hi");
         assert_that(&test_map).contains_entry("hello", &mut "hi");
         assert_that(&test_map).contains_entry(&"hello", &"hi");
     }
@@ -359,4 +487,75 @@ mod tests {
 
         assert_that(&test_map).does_not_contain_entry(&"hello", &"hi");
     }
+
+    #[test]
+    fn should_support_hashmap_with_custom_hasher() {
+        let mut test_map = HashMap::with_hasher(RandomState::new());
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map).has_length(1).contains_entry(&"hello", &"hi");
+    }
+
+    #[test]
+    fn should_not_panic_if_btreemap_contains_entry() {
+        let mut test_map = BTreeMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map).has_length(1).does_not_contain_key(&"hey");
+        assert_that(&test_map).contains_key(&"hello").is_equal_to(&"hi");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: btreemap to contain key <\"hello\">\
+                   \n\t but was: <[\"hey\", \"hi\"]>")]
+    fn should_panic_if_btreemap_does_not_contain_key() {
+        let mut test_map = BTreeMap::new();
+        test_map.insert("hi", "hi");
+        test_map.insert("hey", "hey");
+
+        assert_that(&test_map).contains_key(&"hello");
+    }
+
+    fn assert_has_length<'s, A: HashMapAssertions<'s, u8, u8>>(spec: &mut A, expected: usize) {
+        spec.has_length(expected);
+    }
+
+    #[test]
+    fn should_implement_hashmap_assertions_for_map_assertions() {
+        let mut test_map = HashMap::new();
+        test_map.insert(1u8, 1u8);
+
+        let mut test_btree_map = BTreeMap::new();
+        test_btree_map.insert(1u8, 1u8);
+        test_btree_map.insert(2u8, 2u8);
+
+        assert_has_length(&mut assert_that(&test_map), 1);
+        assert_has_length(&mut assert_that(&test_btree_map), 2);
+    }
+
+    #[test]
+    fn should_look_up_string_keys_by_str() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello".to_string(), 1);
+
+        let mut test_btree_map = BTreeMap::new();
+        test_btree_map.insert("hello".to_string(), 2);
+
+        assert_that(&test_map).contains_key("hello").is_equal_to(&1);
+        assert_that(&test_map).does_not_contain_key("hey").contains_entry("hello", 1);
+        assert_that(&test_map).does_not_contain_entry("hello", 2);
+        assert_that(&test_btree_map).contains_key("hello").is_equal_to(&2);
+        assert_that(&test_btree_map).does_not_contain_key("hey").contains_entry("hello", 2);
+        assert_that(&test_btree_map).does_not_contain_entry("hello", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: btreemap to contain key <\"hey\">\
+                   \n\t but was: <[\"hello\"]>")]
+    fn should_panic_if_btreemap_does_not_contain_str_key() {
+        let mut test_map = BTreeMap::new();
+        test_map.insert("hello".to_string(), 1);
+
+        assert_that(&test_map).contains_key("hey");
+    }
 }
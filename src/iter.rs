@@ -1,20 +1,53 @@
-use super::{AssertionFailure, Spec};
+use super::{check, formatter, AssertionFailure, Spec};
 
 use std::borrow::Borrow;
-use std::cmp::PartialEq;
+use std::cmp::{Ordering, PartialEq};
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::hash::Hash;
+use std::vec;
 
 macro_rules! generate_iter_spec_trait {
     ($trait_name:ident) => {
         pub trait $trait_name<'s, T: 's>
             where T: Debug + PartialEq
             {
-                fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E);
-                fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E)
+                fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self;
+                fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
                     where E: IntoIterator<Item = &'s T> + Clone;
-                fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E);
-                fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E)
+                fn contains_exactly_in_any_order<E: 's>(&mut self,
+                                                        expected_values_iter: &'s E)
+                                                        -> &mut Self
+                    where E: IntoIterator<Item = &'s T> + Clone;
+                fn contains_only<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+                    where E: IntoIterator<Item = &'s T> + Clone;
+                fn contains_none_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+                    where E: IntoIterator<Item = &'s T> + Clone;
+                fn contains_sequence<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+                    where E: IntoIterator<Item = &'s T> + Clone;
+                fn contains_subsequence<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+                    where E: IntoIterator<Item = &'s T> + Clone;
+                fn starts_with_items<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+                    where E: IntoIterator<Item = &'s T> + Clone;
+                fn ends_with_items<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+                    where E: IntoIterator<Item = &'s T> + Clone;
+                fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self;
+                fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E) -> &mut Self
                     where E: Iterator<Item = &'s T> + Clone;
+                fn has_no_duplicates(&mut self) -> &mut Self where T: PartialEq;
+                fn has_no_duplicates_by_key<F, K>(&mut self, key_function: F) -> &mut Self
+                    where F: Fn(&T) -> K,
+                          K: Hash + Eq + Debug;
+                fn has_no_duplicates_by<F>(&mut self, same: F) -> &mut Self
+                    where F: Fn(&T, &T) -> bool;
+                fn is_sorted(&mut self) -> &mut Self where T: PartialOrd;
+                fn is_sorted_descending(&mut self) -> &mut Self where T: PartialOrd;
+                fn is_strictly_increasing(&mut self) -> &mut Self where T: PartialOrd;
+                fn is_sorted_by<F>(&mut self, compare: F) -> &mut Self
+                    where F: Fn(&T, &T) -> Ordering;
+                fn is_sorted_by_key<F, K>(&mut self, key_function: F) -> &mut Self
+                    where F: Fn(&T) -> K,
+                          K: PartialOrd;
             }
     }
 }
@@ -25,15 +58,25 @@ generate_iter_spec_trait!(ContainingIteratorAssertions);
 pub trait MappingIterAssertions<'s, T: 's>
     where T: Debug
 {
-    fn matching_contains<F>(&mut self, matcher: F) where F: Fn(&'s T) -> bool;
-    fn mapped_contains<F, M: 's>(&mut self, mapping_function: F, expected_value: &M)
+    fn matching_contains<F>(&mut self, matcher: F) -> &mut Self where F: Fn(&T) -> bool;
+    fn mapped_contains<F, M: 's>(&mut self, mapping_function: F, expected_value: &M) -> &mut Self
         where M: Debug + PartialEq,
-              F: Fn(&'s T) -> M;
+              F: Fn(&T) -> M;
+    fn all_satisfy<F>(&mut self, assertions: F) -> &mut Self where F: Fn(&T);
+    fn none_match<F>(&mut self, matcher: F) -> &mut Self where F: Fn(&T) -> bool;
+    fn exactly_n_match<F>(&mut self, expected_count: usize, matcher: F) -> &mut Self
+        where F: Fn(&T) -> bool;
+    fn single(&mut self) -> Spec<'_, T>;
+    fn filtered_on<'a, F>(&'a mut self, predicate: F) -> Spec<'a, Vec<&'a T>>
+        where F: Fn(&T) -> bool;
+    fn extracting<'a, F, U: 'a>(&'a mut self, extractor: F) -> Spec<'a, Vec<U>>
+        where F: Fn(&'a T) -> U,
+              T: 'a;
 }
 
 impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
     where T: Debug + PartialEq,
-          &'s I: IntoIterator<Item = &'s T>
+          for<'a> &'a I: IntoIterator<Item = &'a T>
 {
     /// Asserts that the subject contains the provided value. The subject must implement
     /// `IntoIterator`, and the contained type must implement `PartialEq` and `Debug`.
@@ -42,9 +85,11 @@ impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec).contains(&2);
     /// ```
-    fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E) {
-        let subject_iter = self.subject.into_iter();
+    fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self {
+        let subject_iter = self.subject().into_iter();
         check_iterator_contains(self, subject_iter, expected_value, true);
+
+        self
     }
 
     /// Asserts that the subject contains all of the provided values. The subject must implement
@@ -54,12 +99,136 @@ impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec).contains_all_of(&vec![2, 3]);
     /// ```
-    fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E)
+    fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
         where E: IntoIterator<Item = &'s T> + Clone
     {
-        let subject_iter = self.subject.into_iter();
-        let expected_iter = expected_values_iter.clone().into_iter();
+        let subject_iter = self.subject().into_iter();
+        let expected_iter = expected_items(expected_values_iter);
         check_iterator_contains_all_of(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the subject contains exactly the provided values, in any order. Each
+    /// expected value must be matched by a separate item, so duplicates are significant. The
+    /// contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3];
+    /// assert_that(&test_vec).contains_exactly_in_any_order(&vec![3, 1, 2]);
+    /// ```
+    fn contains_exactly_in_any_order<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().into_iter();
+        let expected_iter = expected_items(expected_values_iter);
+        check_iterator_contains_exactly_in_any_order(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the subject contains all of the provided values and nothing else. Unlike
+    /// `contains_exactly_in_any_order`, duplicates are ignored. The contained type must
+    /// implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,2,1];
+    /// assert_that(&test_vec).contains_only(&vec![1, 2]);
+    /// ```
+    fn contains_only<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().into_iter();
+        let expected_iter = expected_items(expected_values_iter);
+        check_iterator_contains_only(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the subject contains none of the provided values. The contained type
+    /// must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3];
+    /// assert_that(&test_vec).contains_none_of(&vec![4, 5]);
+    /// ```
+    fn contains_none_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().into_iter();
+        let expected_iter = expected_items(expected_values_iter);
+        check_iterator_contains_none_of(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the subject contains the provided values as a contiguous run, in the
+    /// same order. The contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3,4];
+    /// assert_that(&test_vec).contains_sequence(&vec![&2, &3]);
+    /// ```
+    fn contains_sequence<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().into_iter();
+        let expected_iter = expected_items(expected_values_iter);
+        check_iterator_contains_sequence(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the subject contains the provided values in the same order, although
+    /// other items may appear between them. The contained type must implement `PartialEq` and
+    /// `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3,4];
+    /// assert_that(&test_vec).contains_subsequence(&vec![&1, &3, &4]);
+    /// ```
+    fn contains_subsequence<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().into_iter();
+        let expected_iter = expected_items(expected_values_iter);
+        check_iterator_contains_subsequence(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the first items of the subject are the provided values, in the same
+    /// order. The contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3,4];
+    /// assert_that(&test_vec).starts_with_items(&vec![&1, &2]);
+    /// ```
+    fn starts_with_items<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().into_iter();
+        let expected_iter = expected_items(expected_values_iter);
+        check_iterator_starts_with_items(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the last items of the subject are the provided values, in the same
+    /// order. The contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3,4];
+    /// assert_that(&test_vec).ends_with_items(&vec![&3, &4]);
+    /// ```
+    fn ends_with_items<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().into_iter();
+        let expected_iter = expected_items(expected_values_iter);
+        check_iterator_ends_with_items(self, subject_iter, expected_iter);
+
+        self
     }
 
     /// Asserts that the subject does not contain the provided value. The subject must implement
@@ -69,9 +238,11 @@ impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec).does_not_contain(&4);
     /// ```
-    fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E) {
-        let subject_iter = self.subject.into_iter();
+    fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self {
+        let subject_iter = self.subject().into_iter();
         check_iterator_contains(self, subject_iter, expected_value, false);
+
+        self
     }
 
     /// Asserts that the subject is equal to provided iterator. The subject must implement
@@ -83,10 +254,138 @@ impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec).equals_iterator(&expected_vec.iter());
     /// ```
-    fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E)
+    fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E) -> &mut Self
         where E: Iterator<Item = &'s T> + Clone
     {
-        compare_iterators(self, self.subject.into_iter(), expected_iter.clone());
+        compare_iterators(self, self.subject().into_iter(), expected_items(expected_iter));
+
+        self
+    }
+
+    /// Asserts that no two items of the subject are equal. The contained type must implement
+    /// `PartialEq` and `Debug`. Every pair of items is compared, so for large collections of items
+    /// implementing `Hash` and `Eq`, `has_no_duplicates_by_key` can hash them instead.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3];
+    /// assert_that(&test_vec).has_no_duplicates();
+    /// ```
+    fn has_no_duplicates(&mut self) -> &mut Self
+        where T: PartialEq
+    {
+        let subject_iter = self.subject().into_iter();
+        check_iterator_has_no_duplicates(self, subject_iter);
+
+        self
+    }
+
+    /// Asserts that no two items of the subject have equal keys, as returned by the provided
+    /// function. The key type must implement `Hash`, `Eq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&test_vec).has_no_duplicates_by_key(|user| user.id);
+    /// ```
+    fn has_no_duplicates_by_key<F, K>(&mut self, key_function: F) -> &mut Self
+        where F: Fn(&T) -> K,
+              K: Hash + Eq + Debug
+    {
+        let subject_iter = self.subject().into_iter();
+        check_iterator_has_no_duplicates_by_key(self, subject_iter, key_function);
+
+        self
+    }
+
+    /// Asserts that no two items of the subject are the same according to the provided
+    /// function. Unlike `has_no_duplicates`, this only needs the contained type to implement
+    /// `Debug`, but compares every pair of items.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1.5, 2.5];
+    /// assert_that(&test_vec).has_no_duplicates_by(|a, b| a == b);
+    /// ```
+    fn has_no_duplicates_by<F>(&mut self, same: F) -> &mut Self
+        where F: Fn(&T, &T) -> bool
+    {
+        let subject_iter = self.subject().into_iter();
+        check_iterator_has_no_duplicates_by(self, subject_iter, same);
+
+        self
+    }
+
+    /// Asserts that the subject is sorted in ascending order, with each item less than or equal
+    /// to the next. The contained type must implement `PartialOrd` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3]).is_sorted();
+    /// ```
+    fn is_sorted(&mut self) -> &mut Self
+        where T: PartialOrd
+    {
+        let subject_iter = self.subject().into_iter();
+        check_iterator_is_sorted(self, subject_iter);
+
+        self
+    }
+
+    /// Asserts that the subject is sorted in descending order, with each item greater than or
+    /// equal to the next. The contained type must implement `PartialOrd` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![3, 2, 1]).is_sorted_descending();
+    /// ```
+    fn is_sorted_descending(&mut self) -> &mut Self
+        where T: PartialOrd
+    {
+        let subject_iter = self.subject().into_iter();
+        check_iterator_is_sorted_descending(self, subject_iter);
+
+        self
+    }
+
+    /// Asserts that each item of the subject is strictly less than the next. The contained
+    /// type must implement `PartialOrd` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3]).is_strictly_increasing();
+    /// ```
+    fn is_strictly_increasing(&mut self) -> &mut Self
+        where T: PartialOrd
+    {
+        let subject_iter = self.subject().into_iter();
+        check_iterator_is_strictly_increasing(self, subject_iter);
+
+        self
+    }
+
+    /// Asserts that the subject is sorted according to the provided comparison function, with
+    /// no item comparing as `Ordering::Greater` than the next.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3]).is_sorted_by(|a, b| a.cmp(b));
+    /// ```
+    fn is_sorted_by<F>(&mut self, compare: F) -> &mut Self
+        where F: Fn(&T, &T) -> Ordering
+    {
+        let subject_iter = self.subject().into_iter();
+        check_iterator_is_sorted_by(self, subject_iter, compare);
+
+        self
+    }
+
+    /// Asserts that the subject is sorted in ascending order of the keys returned by the
+    /// provided function.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&users).is_sorted_by_key(|user| user.age);
+    /// ```
+    fn is_sorted_by_key<F, K>(&mut self, key_function: F) -> &mut Self
+        where F: Fn(&T) -> K,
+              K: PartialOrd
+    {
+        let subject_iter = self.subject().into_iter();
+        check_iterator_is_sorted_by_key(self, subject_iter, key_function);
+
+        self
     }
 }
 
@@ -101,9 +400,11 @@ impl<'s, T: 's, I> ContainingIteratorAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec.iter()).contains(&2);
     /// ```
-    fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E) {
-        let subject_iter = self.subject.clone();
+    fn contains<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self {
+        let subject_iter = self.subject().clone();
         check_iterator_contains(self, subject_iter, expected_value, true);
+
+        self
     }
 
     /// Asserts that the subject contains all of the provided values. The subject must implement
@@ -113,12 +414,136 @@ impl<'s, T: 's, I> ContainingIteratorAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec.iter()).contains_all_of(&vec![2, 3]);
     /// ```
-    fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E)
+    fn contains_all_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
         where E: IntoIterator<Item = &'s T> + Clone
     {
-        let subject_iter = self.subject.clone();
+        let subject_iter = self.subject().clone();
         let expected_iter = expected_values_iter.clone().into_iter();
         check_iterator_contains_all_of(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the iterable subject contains exactly the provided values, in any order. Each
+    /// expected value must be matched by a separate item, so duplicates are significant. The
+    /// contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3];
+    /// assert_that(&test_vec.iter()).contains_exactly_in_any_order(&vec![3, 1, 2]);
+    /// ```
+    fn contains_exactly_in_any_order<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().clone();
+        let expected_iter = expected_values_iter.clone().into_iter();
+        check_iterator_contains_exactly_in_any_order(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the iterable subject contains all of the provided values and nothing else.
+    /// Unlike `contains_exactly_in_any_order`, duplicates are ignored. The contained type must
+    /// implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,2,1];
+    /// assert_that(&test_vec.iter()).contains_only(&vec![1, 2]);
+    /// ```
+    fn contains_only<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().clone();
+        let expected_iter = expected_values_iter.clone().into_iter();
+        check_iterator_contains_only(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the iterable subject contains none of the provided values. The contained type
+    /// must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3];
+    /// assert_that(&test_vec.iter()).contains_none_of(&vec![4, 5]);
+    /// ```
+    fn contains_none_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().clone();
+        let expected_iter = expected_values_iter.clone().into_iter();
+        check_iterator_contains_none_of(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the iterable subject contains the provided values as a contiguous run, in the
+    /// same order. The contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3,4];
+    /// assert_that(&test_vec.iter()).contains_sequence(&vec![&2, &3]);
+    /// ```
+    fn contains_sequence<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().clone();
+        let expected_iter = expected_values_iter.clone().into_iter();
+        check_iterator_contains_sequence(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the iterable subject contains the provided values in the same order, although
+    /// other items may appear between them. The contained type must implement `PartialEq` and
+    /// `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3,4];
+    /// assert_that(&test_vec.iter()).contains_subsequence(&vec![&1, &3, &4]);
+    /// ```
+    fn contains_subsequence<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().clone();
+        let expected_iter = expected_values_iter.clone().into_iter();
+        check_iterator_contains_subsequence(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the first items of the iterable subject are the provided values, in the same
+    /// order. The contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3,4];
+    /// assert_that(&test_vec.iter()).starts_with_items(&vec![&1, &2]);
+    /// ```
+    fn starts_with_items<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().clone();
+        let expected_iter = expected_values_iter.clone().into_iter();
+        check_iterator_starts_with_items(self, subject_iter, expected_iter);
+
+        self
+    }
+
+    /// Asserts that the last items of the iterable subject are the provided values, in the same
+    /// order. The contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3,4];
+    /// assert_that(&test_vec.iter()).ends_with_items(&vec![&3, &4]);
+    /// ```
+    fn ends_with_items<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where E: IntoIterator<Item = &'s T> + Clone
+    {
+        let subject_iter = self.subject().clone();
+        let expected_iter = expected_values_iter.clone().into_iter();
+        check_iterator_ends_with_items(self, subject_iter, expected_iter);
+
+        self
     }
 
     /// Asserts that the iterable subject does not contain the provided value. The subject must
@@ -128,9 +553,11 @@ impl<'s, T: 's, I> ContainingIteratorAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec.iter()).does_not_contain(&4);
     /// ```
-    fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E) {
-        let subject_iter = self.subject.clone();
+    fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E) -> &mut Self {
+        let subject_iter = self.subject().clone();
         check_iterator_contains(self, subject_iter, expected_value, false);
+
+        self
     }
 
     /// Asserts that the iterable subject is equal to provided iterator. The subject must implement
@@ -142,16 +569,145 @@ impl<'s, T: 's, I> ContainingIteratorAssertions<'s, T> for Spec<'s, I>
     /// let test_vec = vec![1,2,3];
     /// assert_that(&test_vec.iter()).equals_iterator(&expected_vec.iter());
     /// ```
-    fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E)
+    fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E) -> &mut Self
         where E: Iterator<Item = &'s T> + Clone
     {
-        compare_iterators(self, self.subject.clone(), expected_iter.clone());
+        compare_iterators(self, self.subject().clone(), expected_iter.clone());
+
+        self
+    }
+
+    /// Asserts that no two items of the iterable subject are equal. The contained type must
+    /// implement `PartialEq` and `Debug`. Every pair of items is compared, so for large
+    /// collections of items implementing `Hash` and `Eq`, `has_no_duplicates_by_key` can hash
+    /// them instead.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3];
+    /// assert_that(&test_vec.iter()).has_no_duplicates();
+    /// ```
+    fn has_no_duplicates(&mut self) -> &mut Self
+        where T: PartialEq
+    {
+        let subject_iter = self.subject().clone();
+        check_iterator_has_no_duplicates(self, subject_iter);
+
+        self
+    }
+
+    /// Asserts that no two items of the iterable subject have equal keys, as returned by the
+    /// provided function. The key type must implement `Hash`, `Eq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&test_vec.iter()).has_no_duplicates_by_key(|user| user.id);
+    /// ```
+    fn has_no_duplicates_by_key<F, K>(&mut self, key_function: F) -> &mut Self
+        where F: Fn(&T) -> K,
+              K: Hash + Eq + Debug
+    {
+        let subject_iter = self.subject().clone();
+        check_iterator_has_no_duplicates_by_key(self, subject_iter, key_function);
+
+        self
+    }
+
+    /// Asserts that no two items of the iterable subject are the same according to the provided
+    /// function. Unlike `has_no_duplicates`, this only needs the contained type to implement
+    /// `Debug`, but compares every pair of items.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1.5, 2.5];
+    /// assert_that(&test_vec.iter()).has_no_duplicates_by(|a, b| a == b);
+    /// ```
+    fn has_no_duplicates_by<F>(&mut self, same: F) -> &mut Self
+        where F: Fn(&T, &T) -> bool
+    {
+        let subject_iter = self.subject().clone();
+        check_iterator_has_no_duplicates_by(self, subject_iter, same);
+
+        self
+    }
+
+    /// Asserts that the iterable subject is sorted in ascending order, with each item less than or
+    /// equal to the next. The contained type must implement `PartialOrd` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3].iter()).is_sorted();
+    /// ```
+    fn is_sorted(&mut self) -> &mut Self
+        where T: PartialOrd
+    {
+        let subject_iter = self.subject().clone();
+        check_iterator_is_sorted(self, subject_iter);
+
+        self
+    }
+
+    /// Asserts that the iterable subject is sorted in descending order, with each item greater than
+    /// or equal to the next. The contained type must implement `PartialOrd` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![3, 2, 1].iter()).is_sorted_descending();
+    /// ```
+    fn is_sorted_descending(&mut self) -> &mut Self
+        where T: PartialOrd
+    {
+        let subject_iter = self.subject().clone();
+        check_iterator_is_sorted_descending(self, subject_iter);
+
+        self
+    }
+
+    /// Asserts that each item of the iterable subject is strictly less than the next. The contained
+    /// type must implement `PartialOrd` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3].iter()).is_strictly_increasing();
+    /// ```
+    fn is_strictly_increasing(&mut self) -> &mut Self
+        where T: PartialOrd
+    {
+        let subject_iter = self.subject().clone();
+        check_iterator_is_strictly_increasing(self, subject_iter);
+
+        self
+    }
+
+    /// Asserts that the iterable subject is sorted according to the provided comparison function,
+    /// with no item comparing as `Ordering::Greater` than the next.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3].iter()).is_sorted_by(|a, b| a.cmp(b));
+    /// ```
+    fn is_sorted_by<F>(&mut self, compare: F) -> &mut Self
+        where F: Fn(&T, &T) -> Ordering
+    {
+        let subject_iter = self.subject().clone();
+        check_iterator_is_sorted_by(self, subject_iter, compare);
+
+        self
+    }
+
+    /// Asserts that the iterable subject is sorted in ascending order of the keys returned by the
+    /// provided function.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&users).is_sorted_by_key(|user| user.age);
+    /// ```
+    fn is_sorted_by_key<F, K>(&mut self, key_function: F) -> &mut Self
+        where F: Fn(&T) -> K,
+              K: PartialOrd
+    {
+        let subject_iter = self.subject().clone();
+        check_iterator_is_sorted_by_key(self, subject_iter, key_function);
+
+        self
     }
 }
 
 impl<'s, T: 's, I> MappingIterAssertions<'s, T> for Spec<'s, I>
     where T: Debug,
-          &'s I: IntoIterator<Item = &'s T>
+          for<'a> &'a I: IntoIterator<Item = &'a T>
 {
     /// Maps the values of the subject before asserting that the mapped subject contains the
     /// provided value. The subject must implement IntoIterator, and the type of the mapped
@@ -168,20 +724,22 @@ impl<'s, T: 's, I> MappingIterAssertions<'s, T> for Spec<'s, I>
     ///
     /// ...
     ///
-    /// assert_that(&vec![Simple { val: 1 }, Simple { val: 2 } ]).mapped_contains(|x| &x.val, &2);
+    /// assert_that(&vec![Simple { val: 1 }, Simple { val: 2 } ]).mapped_contains(|x| x.val, &2);
     /// ```
-    fn mapped_contains<F, M: 's>(&mut self, mapping_function: F, expected_value: &M)
+    fn mapped_contains<F, M: 's>(&mut self, mapping_function: F, expected_value: &M) -> &mut Self
         where M: Debug + PartialEq,
-              F: Fn(&'s T) -> M
+              F: Fn(&T) -> M
     {
-        let subject = self.subject;
+        let subject = self.subject();
 
         let mapped_vec: Vec<M> = subject.into_iter().map(mapping_function).collect();
         if mapped_vec.contains(expected_value) {
-            return;
+            return self;
         }
 
-        panic_unmatched(self, expected_value, mapped_vec, true);
+        panic_unmatched(self, "mapped_contains", expected_value, mapped_vec, true);
+
+        self
     }
 
     /// Asserts that the subject contains a matching item by using the provided function.
@@ -200,24 +758,212 @@ impl<'s, T: 's, I> MappingIterAssertions<'s, T> for Spec<'s, I>
     ///     }
     /// });
     /// ```
-    fn matching_contains<F>(&mut self, matcher: F)
-        where F: Fn(&'s T) -> bool
+    fn matching_contains<F>(&mut self, matcher: F) -> &mut Self
+        where F: Fn(&T) -> bool
     {
-        let mut actual = Vec::new();
-        for x in self.subject {
-            if matcher(x) {
-                return;
-            } else {
-                actual.push(x);
-            }
+        if self.subject().into_iter().any(&matcher) {
+            return self;
         }
+
+        let actual: Vec<&T> = self.subject().into_iter().collect();
         AssertionFailure::from_spec(self)
+            .with_assertion_name("matching_contains")
             .fail_with_message(format!("expectation failed for iterator with values <{:?}>",
                                        actual));
+
+        self
+    }
+
+    /// Asserts that every item of the subject passes the assertions made by the provided
+    /// function. The subject must implement `IntoIterator`, and the contained type must
+    /// implement `Debug`.
+    ///
+    /// Every item is checked, and the failure lists each failing item along with the message of
+    /// its failed assertion.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&users).all_satisfy(|user| {
+    ///     assert_that(&user.age).is_greater_than(&17);
+    /// });
+    /// ```
+    fn all_satisfy<F>(&mut self, assertions: F) -> &mut Self
+        where F: Fn(&T)
+    {
+        let mut item_count = 0;
+        let mut failures = vec![];
+
+        for (index, item) in self.subject().into_iter().enumerate() {
+            item_count += 1;
+
+            if let Err(error) = check(|| assertions(item)) {
+                let message = formatter::format_failure(&error);
+                let separator = if message.starts_with('\n') { "" } else { " " };
+
+                failures.push(format!("item at index <{}>: <{:?}>{}{}",
+                                      index,
+                                      item,
+                                      separator,
+                                      message.trim_end()));
+            }
+        }
+
+        if !failures.is_empty() {
+            let mut failure = AssertionFailure::from_spec(self);
+            failure.with_assertion_name("all_satisfy")
+                .with_expected("all items to satisfy the assertions".to_string())
+                .with_actual(format!("<{}> of <{}> items did not", failures.len(), item_count));
+
+            for item_failure in failures {
+                failure.with_context(item_failure);
+            }
+
+            failure.fail();
+        }
+
+        self
+    }
+
+    /// Asserts that no item of the subject matches the provided function. The subject must
+    /// implement `IntoIterator`, and the contained type must implement `Debug`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 3, 5]).none_match(|val| val % 2 == 0);
+    /// ```
+    fn none_match<F>(&mut self, matcher: F) -> &mut Self
+        where F: Fn(&T) -> bool
+    {
+        let subject_iter = self.subject().into_iter();
+        check_iterator_match_count(self, "none_match", subject_iter, 0, matcher);
+
+        self
+    }
+
+    /// Asserts that exactly the provided number of items of the subject match the provided
+    /// function. The subject must implement `IntoIterator`, and the contained type must
+    /// implement `Debug`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3, 4]).exactly_n_match(2, |val| val % 2 == 0);
+    /// ```
+    fn exactly_n_match<F>(&mut self, expected_count: usize, matcher: F) -> &mut Self
+        where F: Fn(&T) -> bool
+    {
+        let subject_iter = self.subject().into_iter();
+        check_iterator_match_count(self, "exactly_n_match", subject_iter, expected_count, matcher);
+
+        self
+    }
+
+    /// Asserts that the subject contains exactly one item. The subject must implement
+    /// `IntoIterator`, and the contained type must implement `Debug`.
+    ///
+    /// This will return a new `Spec` containing the item.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1]).single().is_equal_to(&1);
+    /// ```
+    fn single(&mut self) -> Spec<'_, T> {
+        let items: Vec<&T> = self.subject().into_iter().collect();
+
+        if items.len() != 1 {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("single")
+                .with_expected("iterator to contain a single item".to_string())
+                .with_actual(format!("<{}> items in <{:?}>", items.len(), items))
+                .fail_fatal();
+        }
+
+        self.derive(items[0])
+    }
+
+    /// Creates a new `Spec` containing the items of the subject which match the provided
+    /// function. The subject must implement `IntoIterator`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&users).filtered_on(|user| user.is_admin).has_length(1);
+    /// ```
+    fn filtered_on<'a, F>(&'a mut self, predicate: F) -> Spec<'a, Vec<&'a T>>
+        where F: Fn(&T) -> bool
+    {
+        let spec: &'a Self = self;
+        let filtered: Vec<&'a T> = spec.subject()
+            .into_iter()
+            .filter(|item| predicate(item))
+            .collect();
+        spec.derive_owned(filtered)
+    }
+
+    /// Creates a new `Spec` containing the values returned by the provided function for each
+    /// item of the subject, which may borrow from the item. The subject must implement
+    /// `IntoIterator`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&users).extracting(|user| user.name.as_str()).contains("bob");
+    /// ```
+    fn extracting<'a, F, U: 'a>(&'a mut self, extractor: F) -> Spec<'a, Vec<U>>
+        where F: Fn(&'a T) -> U,
+              T: 'a
+    {
+        let spec: &'a Self = self;
+        let extracted: Vec<U> = spec.subject().into_iter().map(extractor).collect();
+        spec.derive_owned(extracted)
+    }
+}
+
+/// Collects the expected values of an assertion against an `IntoIterator` subject. The items of
+/// the subject are only borrowed for as long as the `Spec` is, and collecting the expected values
+/// allows them to be compared for that shorter time.
+fn expected_items<'s, T, E>(expected_values_iter: &'s E) -> vec::IntoIter<&'s T>
+    where E: IntoIterator<Item = &'s T> + Clone
+{
+    let expected_values: Vec<&'s T> = expected_values_iter.clone().into_iter().collect();
+    expected_values.into_iter()
+}
+
+fn check_iterator_match_count<T, V, I, F>(spec: &Spec<T>,
+                                          assertion_name: &'static str,
+                                          actual_iter: I,
+                                          expected_count: usize,
+                                          matcher: F)
+    where V: Debug + Copy,
+          I: Iterator<Item = V>,
+          F: Fn(V) -> bool
+{
+    let actual_values: Vec<V> = actual_iter.collect();
+
+    let matched_indexes: Vec<usize> = actual_values.iter()
+        .enumerate()
+        .filter(|&(_, value)| matcher(*value))
+        .map(|(index, _)| index)
+        .collect();
+
+    if matched_indexes.len() != expected_count {
+        let matched_items: Vec<String> = matched_indexes.iter()
+            .map(|&index| format!("item at index <{}>: <{:?}>", index, actual_values[index]))
+            .collect();
+
+        let expected = if expected_count == 0 {
+            "no items to match".to_string()
+        } else {
+            format!("<{}> items to match", expected_count)
+        };
+
+        let mut failure = AssertionFailure::from_spec(spec);
+        failure.with_assertion_name(assertion_name)
+            .with_expected(expected)
+            .with_actual(format!("<{}> items matched in <{:?}>",
+                                 matched_indexes.len(),
+                                 actual_values));
+
+        if !matched_items.is_empty() {
+            failure.with_context(format!("matching items:\n{}", matched_items.join("\n")));
+        }
+
+        failure.fail();
     }
 }
 
-fn check_iterator_contains<'s, T, V: 's, I, E: Borrow<V>>(spec: &mut Spec<T>,
+fn check_iterator_contains<'s, T, V: 's, I, E: Borrow<V>>(spec: &Spec<T>,
                                                           actual_iter: I,
                                                           expected_value: E,
                                                           should_contain: bool)
@@ -230,19 +976,237 @@ fn check_iterator_contains<'s, T, V: 's, I, E: Borrow<V>>(spec: &mut Spec<T>,
     let mut actual = Vec::new();
 
     for x in actual_iter {
-        if borrowed_expected_value.eq(&x) {
+        if borrowed_expected_value.eq(x) {
             contains_value = true;
         }
 
-        actual.push(x);
-    }
+        actual.push(x);
+    }
+
+    if contains_value != should_contain {
+        let assertion_name = if should_contain { "contains" } else { "does_not_contain" };
+        panic_unmatched(spec, assertion_name, borrowed_expected_value, actual, should_contain);
+    }
+}
+
+fn check_iterator_contains_all_of<T, V, I, E>(spec: &Spec<T>,
+                                              actual_iter: I,
+                                              expected_values_iter: E)
+    where V: PartialEq + Debug,
+          I: Iterator<Item = V>,
+          E: Iterator<Item = V>
+{
+    let actual_values: Vec<V> = actual_iter.collect();
+    let matches = match_expected_values(&actual_values, expected_values_iter);
+
+    if !matches.unmatched_values.is_empty() {
+        let mut expected_values: Vec<V> = matches.matched_values;
+        expected_values.extend(matches.unmatched_values);
+
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name("contains_all_of")
+            .with_expected(format!("iterator to contain items <{:?}>", expected_values))
+            .with_actual(format!("<{:?}>", actual_values))
+            .fail();
+    }
+}
+
+fn check_iterator_contains_exactly_in_any_order<T, V, I, E>(spec: &Spec<T>,
+                                                            actual_iter: I,
+                                                            expected_values_iter: E)
+    where V: PartialEq + Debug,
+          I: Iterator<Item = V>,
+          E: Iterator<Item = V>
+{
+    let actual_values: Vec<V> = actual_iter.collect();
+    let matches = match_expected_values(&actual_values, expected_values_iter);
+
+    let unexpected_values: Vec<&V> = actual_values.iter()
+        .enumerate()
+        .filter(|&(index, _)| !matches.matched_indexes.contains(&index))
+        .map(|(_, value)| value)
+        .collect();
+
+    if !matches.unmatched_values.is_empty() || !unexpected_values.is_empty() {
+        let mut expected_values: Vec<&V> = matches.matched_values.iter().collect();
+        expected_values.extend(matches.unmatched_values.iter());
+
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name("contains_exactly_in_any_order")
+            .with_expected(format!("iterator to contain exactly <{:?}> in any order",
+                                   expected_values))
+            .with_actual(format!("<{:?}>", actual_values))
+            .with_context(describe_missing_and_unexpected(&matches.unmatched_values,
+                                                          &unexpected_values))
+            .fail();
+    }
+}
+
+fn check_iterator_contains_only<T, V, I, E>(spec: &Spec<T>,
+                                            actual_iter: I,
+                                            expected_values_iter: E)
+    where V: PartialEq + Debug,
+          I: Iterator<Item = V>,
+          E: Iterator<Item = V>
+{
+    let actual_values: Vec<V> = actual_iter.collect();
+    let expected_values: Vec<V> = expected_values_iter.collect();
+
+    let missing_values: Vec<&V> = expected_values.iter()
+        .filter(|expected| !actual_values.contains(expected))
+        .collect();
+    let unexpected_values: Vec<&V> = actual_values.iter()
+        .filter(|actual| !expected_values.contains(actual))
+        .collect();
+
+    if !missing_values.is_empty() || !unexpected_values.is_empty() {
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name("contains_only")
+            .with_expected(format!("iterator to contain only <{:?}>", expected_values))
+            .with_actual(format!("<{:?}>", actual_values))
+            .with_context(describe_missing_and_unexpected(&missing_values, &unexpected_values))
+            .fail();
+    }
+}
+
+fn check_iterator_contains_none_of<T, V, I, E>(spec: &Spec<T>,
+                                               actual_iter: I,
+                                               expected_values_iter: E)
+    where V: PartialEq + Debug,
+          I: Iterator<Item = V>,
+          E: Iterator<Item = V>
+{
+    let actual_values: Vec<V> = actual_iter.collect();
+    let expected_values: Vec<V> = expected_values_iter.collect();
+
+    let unexpected_values: Vec<&V> = actual_values.iter()
+        .filter(|actual| expected_values.contains(actual))
+        .collect();
+
+    if !unexpected_values.is_empty() {
+        let missing_values: Vec<&V> = vec![];
+
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name("contains_none_of")
+            .with_expected(format!("iterator to contain none of <{:?}>", expected_values))
+            .with_actual(format!("<{:?}>", actual_values))
+            .with_context(describe_missing_and_unexpected(&missing_values, &unexpected_values))
+            .fail();
+    }
+}
+
+fn check_iterator_contains_sequence<T, V, I, E>(spec: &Spec<T>,
+                                               actual_iter: I,
+                                               expected_values_iter: E)
+    where V: PartialEq + Debug,
+          I: Iterator<Item = V>,
+          E: Iterator<Item = V>
+{
+    let actual_values: Vec<V> = actual_iter.collect();
+    let expected_values: Vec<V> = expected_values_iter.collect();
+
+    let mut longest_match = (0, 0);
+
+    for start in 0..actual_values.len() {
+        let length = actual_values[start..]
+            .iter()
+            .zip(expected_values.iter())
+            .take_while(|&(actual, expected)| actual.eq(expected))
+            .count();
+
+        if length > longest_match.1 {
+            longest_match = (start, length);
+        }
+    }
+
+    let (start, length) = longest_match;
+
+    if length < expected_values.len() {
+        let partial_match = if length == 0 {
+            "no partial match".to_string()
+        } else {
+            format!("longest partial match: <{:?}> at index <{}>",
+                    &actual_values[start..start + length],
+                    start)
+        };
+
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name("contains_sequence")
+            .with_expected(format!("iterator to contain sequence <{:?}>", expected_values))
+            .with_actual(format!("<{:?}>", actual_values))
+            .with_context(partial_match)
+            .fail();
+    }
+}
+
+fn check_iterator_contains_subsequence<T, V, I, E>(spec: &Spec<T>,
+                                                  actual_iter: I,
+                                                  expected_values_iter: E)
+    where V: PartialEq + Debug,
+          I: Iterator<Item = V>,
+          E: Iterator<Item = V>
+{
+    let actual_values: Vec<V> = actual_iter.collect();
+    let expected_values: Vec<V> = expected_values_iter.collect();
+
+    let mut matched_indexes = vec![];
+    let mut expected_iter = expected_values.iter().peekable();
+
+    for (index, actual) in actual_values.iter().enumerate() {
+        match expected_iter.peek() {
+            Some(expected) if actual.eq(expected) => {
+                matched_indexes.push(index);
+                expected_iter.next();
+            }
+            Some(_) => {}
+            None => break,
+        }
+    }
+
+    if matched_indexes.len() < expected_values.len() {
+        let partial_match = if matched_indexes.is_empty() {
+            "no partial match".to_string()
+        } else {
+            format!("longest partial match: <{:?}> at indexes <{:?}>",
+                    &expected_values[..matched_indexes.len()],
+                    matched_indexes)
+        };
+
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name("contains_subsequence")
+            .with_expected(format!("iterator to contain subsequence <{:?}>", expected_values))
+            .with_actual(format!("<{:?}>", actual_values))
+            .with_context(partial_match)
+            .fail();
+    }
+}
+
+fn check_iterator_starts_with_items<T, V, I, E>(spec: &Spec<T>,
+                                                actual_iter: I,
+                                                expected_values_iter: E)
+    where V: PartialEq + Debug,
+          I: Iterator<Item = V>,
+          E: Iterator<Item = V>
+{
+    let actual_values: Vec<V> = actual_iter.collect();
+    let expected_values: Vec<V> = expected_values_iter.collect();
+
+    let length = actual_values.iter()
+        .zip(expected_values.iter())
+        .take_while(|&(actual, expected)| actual.eq(expected))
+        .count();
 
-    if contains_value != should_contain {
-        panic_unmatched(spec, borrowed_expected_value, actual, should_contain);
+    if length < expected_values.len() {
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name("starts_with_items")
+            .with_expected(format!("iterator to start with items <{:?}>", expected_values))
+            .with_actual(format!("<{:?}>", actual_values))
+            .with_context(describe_partial_match(&actual_values[..length]))
+            .fail();
     }
 }
 
-fn check_iterator_contains_all_of<T, V, I, E>(spec: &mut Spec<T>,
+fn check_iterator_ends_with_items<T, V, I, E>(spec: &Spec<T>,
                                               actual_iter: I,
                                               expected_values_iter: E)
     where V: PartialEq + Debug,
@@ -250,43 +1214,87 @@ fn check_iterator_contains_all_of<T, V, I, E>(spec: &mut Spec<T>,
           E: Iterator<Item = V>
 {
     let actual_values: Vec<V> = actual_iter.collect();
+    let expected_values: Vec<V> = expected_values_iter.collect();
 
-    let mut matched_indexes = vec![];
-    let mut matched_indexes_holder = vec![];
+    let length = actual_values.iter()
+        .rev()
+        .zip(expected_values.iter().rev())
+        .take_while(|&(actual, expected)| actual.eq(expected))
+        .count();
+
+    if length < expected_values.len() {
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name("ends_with_items")
+            .with_expected(format!("iterator to end with items <{:?}>", expected_values))
+            .with_actual(format!("<{:?}>", actual_values))
+            .with_context(describe_partial_match(&actual_values[actual_values.len() - length..]))
+            .fail();
+    }
+}
 
-    let mut matched_values = vec![];
-    let mut unmatched_values = vec![];
+fn describe_partial_match<V: Debug>(partial_match: &[V]) -> String {
+    if partial_match.is_empty() {
+        "no partial match".to_string()
+    } else {
+        format!("longest partial match: <{:?}>", partial_match)
+    }
+}
+
+/// The result of matching each expected value against a separate actual value.
+struct ExpectedValueMatches<V> {
+    matched_indexes: Vec<usize>,
+    matched_values: Vec<V>,
+    unmatched_values: Vec<V>,
+}
 
-    'outer: for expected in expected_values_iter {
-        matched_indexes.append(&mut matched_indexes_holder);
+fn match_expected_values<V, E>(actual_values: &[V],
+                               expected_values_iter: E)
+                               -> ExpectedValueMatches<V>
+    where V: PartialEq,
+          E: Iterator<Item = V>
+{
+    let mut matches = ExpectedValueMatches {
+        matched_indexes: vec![],
+        matched_values: vec![],
+        unmatched_values: vec![],
+    };
 
-        for (index, actual) in actual_values.iter()
+    for expected in expected_values_iter {
+        let matched_index = actual_values.iter()
             .enumerate()
-            .filter(|&(i, _)| !matched_indexes.contains(&i)) {
-            if expected.eq(actual) {
-                matched_indexes_holder.push(index);
-                matched_values.push(expected);
-                continue 'outer;
+            .position(|(index, actual)| {
+                !matches.matched_indexes.contains(&index) && expected.eq(actual)
+            });
+
+        match matched_index {
+            Some(index) => {
+                matches.matched_indexes.push(index);
+                matches.matched_values.push(expected);
             }
+            None => matches.unmatched_values.push(expected),
         }
-
-        unmatched_values.push(expected);
     }
 
-    if !unmatched_values.is_empty() {
-        let mut expected_values: Vec<V> = vec![];
+    matches
+}
 
-        expected_values.append(&mut matched_values);
-        expected_values.append(&mut unmatched_values);
+fn describe_missing_and_unexpected<M: Debug, U: Debug>(missing: &[M],
+                                                       unexpected: &[U])
+                                                       -> String {
+    let mut lines = vec![];
 
-        AssertionFailure::from_spec(spec)
-            .with_expected(format!("iterator to contain items <{:?}>", expected_values))
-            .with_actual(format!("<{:?}>", actual_values))
-            .fail();
+    if !missing.is_empty() {
+        lines.push(format!("missing items: <{:?}>", missing));
+    }
+
+    if !unexpected.is_empty() {
+        lines.push(format!("unexpected items: <{:?}>", unexpected));
     }
+
+    lines.join("\n")
 }
 
-fn compare_iterators<T, V, I, E>(spec: &mut Spec<T>, actual_iter: I, expected_iter: E)
+fn compare_iterators<T, V, I, E>(spec: &Spec<T>, actual_iter: I, expected_iter: E)
     where V: PartialEq + Debug,
           I: Iterator<Item = V>,
           E: Iterator<Item = V>
@@ -302,6 +1310,7 @@ fn compare_iterators<T, V, I, E>(spec: &mut Spec<T>, actual_iter: I, expected_it
             (Some(actual), Some(expected)) => {
                 if !&actual.eq(&expected) {
                     AssertionFailure::from_spec(spec)
+                        .with_assertion_name("equals_iterator")
                         .with_expected(format!("Iterator item of <{:?}> (read <{:?}>)",
                                                expected,
                                                read_expected))
@@ -310,7 +1319,7 @@ fn compare_iterators<T, V, I, E>(spec: &mut Spec<T>, actual_iter: I, expected_it
                                              read_subject))
                         .fail();
 
-                    unreachable!();
+                    return;
                 }
 
                 read_subject.push(actual);
@@ -318,23 +1327,25 @@ fn compare_iterators<T, V, I, E>(spec: &mut Spec<T>, actual_iter: I, expected_it
             }
             (Some(actual), None) => {
                 AssertionFailure::from_spec(spec)
+                    .with_assertion_name("equals_iterator")
                     .with_expected(format!("Completed iterator (read <{:?}>)", read_expected))
                     .with_actual(format!("Iterator item of <{:?}> (read <{:?}>",
                                          actual,
                                          read_subject))
                     .fail();
 
-                unreachable!();
+                return;
             }
             (None, Some(expected)) => {
                 AssertionFailure::from_spec(spec)
+                    .with_assertion_name("equals_iterator")
                     .with_expected(format!("Iterator item of <{:?}> (read <{:?}>",
                                            expected,
                                            read_expected))
                     .with_actual(format!("Completed iterator (read <{:?}>", read_subject))
                     .fail();
 
-                unreachable!();
+                return;
             }
             (None, None) => {
                 break;
@@ -343,7 +1354,207 @@ fn compare_iterators<T, V, I, E>(spec: &mut Spec<T>, actual_iter: I, expected_it
     }
 }
 
-fn panic_unmatched<T, E: Debug, A: Debug>(spec: &mut Spec<T>,
+fn check_iterator_has_no_duplicates<'a, T, V, I>(spec: &Spec<T>, actual_iter: I)
+    where V: PartialEq + Debug + 'a,
+          I: Iterator<Item = &'a V>
+{
+    let actual_values: Vec<&V> = actual_iter.collect();
+    let duplicates = find_duplicates_by(&actual_values, |a, b| a == b);
+
+    fail_on_duplicates(spec,
+                       "has_no_duplicates",
+                       "duplicated items",
+                       &actual_values,
+                       duplicates);
+}
+
+fn check_iterator_has_no_duplicates_by_key<'a, T, V, K, I, F>(spec: &Spec<T>,
+                                                             actual_iter: I,
+                                                             key_function: F)
+    where V: Debug + 'a,
+          K: Hash + Eq + Debug,
+          I: Iterator<Item = &'a V>,
+          F: Fn(&V) -> K
+{
+    let actual_values: Vec<&V> = actual_iter.collect();
+    let duplicates = find_hashed_duplicates(&actual_values, &key_function);
+
+    fail_on_duplicates(spec,
+                       "has_no_duplicates_by_key",
+                       "duplicated keys",
+                       &actual_values,
+                       duplicates);
+}
+
+fn check_iterator_has_no_duplicates_by<'a, T, V, I, F>(spec: &Spec<T>,
+                                                      actual_iter: I,
+                                                      same: F)
+    where V: Debug + 'a,
+          I: Iterator<Item = &'a V>,
+          F: Fn(&V, &V) -> bool
+{
+    let actual_values: Vec<&V> = actual_iter.collect();
+    let duplicates = find_duplicates_by(&actual_values, same);
+
+    fail_on_duplicates(spec,
+                       "has_no_duplicates_by",
+                       "duplicated items",
+                       &actual_values,
+                       duplicates);
+}
+
+/// Groups the indexes of the values which are the same as an earlier value, comparing each value
+/// with the first of every group found so far, in the order of their first appearance.
+fn find_duplicates_by<'a, V, F>(values: &[&'a V], same: F) -> Vec<(&'a V, Vec<usize>)>
+    where F: Fn(&V, &V) -> bool
+{
+    let mut groups: Vec<(&V, Vec<usize>)> = vec![];
+
+    for (index, value) in values.iter().enumerate() {
+        match groups.iter().position(|(first, _)| same(first, value)) {
+            Some(position) => groups[position].1.push(index),
+            None => groups.push((value, vec![index])),
+        }
+    }
+
+    groups.retain(|(_, indexes)| indexes.len() > 1);
+    groups
+}
+
+/// Groups the indexes of the values by key, keeping only the keys which appear more than once,
+/// in the order of their first appearance.
+fn find_hashed_duplicates<V, K, F>(values: &[V], key_function: F) -> Vec<(K, Vec<usize>)>
+    where V: Copy,
+          K: Hash + Eq,
+          F: Fn(V) -> K
+{
+    let mut indexes_by_key: HashMap<K, Vec<usize>> = HashMap::new();
+
+    for (index, value) in values.iter().enumerate() {
+        indexes_by_key.entry(key_function(*value)).or_default().push(index);
+    }
+
+    let mut duplicates: Vec<(K, Vec<usize>)> = indexes_by_key.into_iter()
+        .filter(|(_, indexes)| indexes.len() > 1)
+        .collect();
+
+    duplicates.sort_by_key(|(_, indexes)| indexes[0]);
+    duplicates
+}
+
+fn fail_on_duplicates<T, V, K>(spec: &Spec<T>,
+                               assertion_name: &'static str,
+                               duplicates_heading: &str,
+                               actual_values: &[V],
+                               duplicates: Vec<(K, Vec<usize>)>)
+    where V: Debug,
+          K: Debug
+{
+    if duplicates.is_empty() {
+        return;
+    }
+
+    let duplicates: Vec<String> = duplicates.iter()
+        .map(|(key, indexes)| format!("<{:?}> at indexes <{:?}>", key, indexes))
+        .collect();
+
+    AssertionFailure::from_spec(spec)
+        .with_assertion_name(assertion_name)
+        .with_expected("iterator to have no duplicates".to_string())
+        .with_actual(format!("<{:?}>", actual_values))
+        .with_context(format!("{}:\n{}", duplicates_heading, duplicates.join("\n")))
+        .fail();
+}
+
+fn check_iterator_is_sorted<T, V, I>(spec: &Spec<T>, actual_iter: I)
+    where V: PartialOrd + Debug,
+          I: Iterator<Item = V>
+{
+    check_iterator_sorted(spec,
+                          "is_sorted",
+                          "in ascending order",
+                          actual_iter,
+                          |first, second| first <= second);
+}
+
+fn check_iterator_is_sorted_descending<T, V, I>(spec: &Spec<T>, actual_iter: I)
+    where V: PartialOrd + Debug,
+          I: Iterator<Item = V>
+{
+    check_iterator_sorted(spec,
+                          "is_sorted_descending",
+                          "in descending order",
+                          actual_iter,
+                          |first, second| first >= second);
+}
+
+fn check_iterator_is_strictly_increasing<T, V, I>(spec: &Spec<T>, actual_iter: I)
+    where V: PartialOrd + Debug,
+          I: Iterator<Item = V>
+{
+    check_iterator_sorted(spec,
+                          "is_strictly_increasing",
+                          "in strictly increasing order",
+                          actual_iter,
+                          |first, second| first < second);
+}
+
+fn check_iterator_is_sorted_by<'a, T, V, I, F>(spec: &Spec<T>, actual_iter: I, compare: F)
+    where V: Debug + 'a,
+          I: Iterator<Item = &'a V>,
+          F: Fn(&V, &V) -> Ordering
+{
+    check_iterator_sorted(spec,
+                          "is_sorted_by",
+                          "by the provided comparison",
+                          actual_iter,
+                          |first, second| compare(first, second) != Ordering::Greater);
+}
+
+fn check_iterator_is_sorted_by_key<'a, T, V, K, I, F>(spec: &Spec<T>,
+                                                     actual_iter: I,
+                                                     key_function: F)
+    where V: Debug + 'a,
+          K: PartialOrd,
+          I: Iterator<Item = &'a V>,
+          F: Fn(&V) -> K
+{
+    check_iterator_sorted(spec,
+                          "is_sorted_by_key",
+                          "by the provided key",
+                          actual_iter,
+                          |first, second| key_function(first) <= key_function(second));
+}
+
+fn check_iterator_sorted<T, V, I, F>(spec: &Spec<T>,
+                                     assertion_name: &'static str,
+                                     order: &str,
+                                     actual_iter: I,
+                                     in_order: F)
+    where V: Debug,
+          I: Iterator<Item = V>,
+          F: Fn(&V, &V) -> bool
+{
+    let actual_values: Vec<V> = actual_iter.collect();
+
+    let out_of_order_index = actual_values.windows(2)
+        .position(|pair| !in_order(&pair[0], &pair[1]));
+
+    if let Some(index) = out_of_order_index {
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name(assertion_name)
+            .with_expected(format!("iterator to be sorted {}", order))
+            .with_actual(format!("<{:?}>, with <{:?}> at index <{}> followed by <{:?}>",
+                                 actual_values,
+                                 actual_values[index],
+                                 index,
+                                 actual_values[index + 1]))
+            .fail();
+    }
+}
+
+fn panic_unmatched<T, E: Debug, A: Debug>(spec: &Spec<T>,
+                                          assertion_name: &'static str,
                                           expected: E,
                                           actual: A,
                                           should_contain: bool) {
@@ -352,6 +1563,7 @@ fn panic_unmatched<T, E: Debug, A: Debug>(spec: &mut Spec<T>,
     };
 
     AssertionFailure::from_spec(spec)
+        .with_assertion_name(assertion_name)
         .with_expected(format!("iterator to{}contain <{:?}>", condition, expected))
         .with_actual(format!("<{:?}>", actual))
         .fail();
@@ -361,6 +1573,7 @@ fn panic_unmatched<T, E: Debug, A: Debug>(spec: &mut Spec<T>,
 mod tests {
 
     use super::super::prelude::*;
+    use super::super::formatter::{reset_thread_formatter, set_thread_formatter, CompactFormatter};
     use std::collections::LinkedList;
 
     #[test]
@@ -576,6 +1789,424 @@ mod tests {
         assert_that(&test_vec).mapped_contains(|val| val.value, &1);
     }
 
+    #[test]
+    fn should_not_panic_if_vec_is_sorted() {
+        let test_vec = vec![1, 2, 2, 3];
+
+        assert_that(&test_vec).is_sorted();
+        assert_that(&test_vec.iter()).is_sorted();
+        assert_that(&vec![3, 2, 2, 1]).is_sorted_descending();
+        assert_that(&vec![1, 2, 3]).is_strictly_increasing();
+    }
+
+    #[test]
+    fn should_not_panic_if_empty_or_single_item_vec_is_sorted() {
+        let empty_vec: Vec<u8> = vec![];
+
+        assert_that(&empty_vec).is_sorted();
+        assert_that(&vec![1]).is_strictly_increasing();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to be sorted in ascending order\
+                   \n\t but was: <[1, 3, 2, 4]>, with <3> at index <1> followed by <2>")]
+    fn should_panic_if_vec_is_not_sorted() {
+        assert_that(&vec![1, 3, 2, 4]).is_sorted();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to be sorted in descending order\
+                   \n\t but was: <[3, 1, 2]>, with <1> at index <1> followed by <2>")]
+    fn should_panic_if_iterator_is_not_sorted_descending() {
+        let test_vec = vec![3, 1, 2];
+        assert_that(&test_vec.iter()).is_sorted_descending();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to be sorted in strictly increasing order\
+                   \n\t but was: <[1, 2, 2]>, with <2> at index <1> followed by <2>")]
+    fn should_panic_if_vec_is_not_strictly_increasing() {
+        assert_that(&vec![1, 2, 2]).is_strictly_increasing();
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_is_sorted_by_comparison_and_key() {
+        let test_vec = vec![TestStruct { value: 6 }, TestStruct { value: 5 }];
+
+        assert_that(&test_vec).is_sorted_by(|a, b| b.value.cmp(&a.value));
+        assert_that(&test_vec).is_sorted_by_key(|val| -(val.value as i16));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to be sorted by the provided key\
+                   \n\t but was: <[TestStruct { value: 5 }, TestStruct { value: 6 }, \
+                   TestStruct { value: 4 }]>, with <TestStruct { value: 6 }> at index <1> \
+                   followed by <TestStruct { value: 4 }>")]
+    fn should_panic_if_vec_is_not_sorted_by_key() {
+        let test_vec =
+            vec![TestStruct { value: 5 }, TestStruct { value: 6 }, TestStruct { value: 4 }];
+        assert_that(&test_vec).is_sorted_by_key(|val| val.value);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to be sorted by the provided comparison\
+                   \n\t but was: <[1, 2]>, with <1> at index <0> followed by <2>")]
+    fn should_panic_if_iterator_is_not_sorted_by_comparison() {
+        let test_vec = vec![1, 2];
+        assert_that(&test_vec.iter()).is_sorted_by(|a, b| b.cmp(a));
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_contains_exactly_expected_values_in_any_order() {
+        let test_vec = vec![1, 2, 2, 3];
+
+        assert_that(&test_vec).contains_exactly_in_any_order(&vec![&2, &3, &1, &2]);
+        assert_that(&test_vec.iter()).contains_exactly_in_any_order(&vec![&2, &1, &3, &2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to contain exactly <[1, 3, 2, 5]> \
+                   in any order\
+                   \n\t but was: <[1, 2, 3, 4]>\
+                   \n\n\tmissing items: <[5]>\
+                   \n\tunexpected items: <[4]>")]
+    fn should_panic_if_vec_does_not_contain_exactly_expected_values() {
+        assert_that(&vec![1, 2, 3, 4]).contains_exactly_in_any_order(&vec![&1, &3, &2, &5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to contain exactly <[1, 2]> in any order\
+                   \n\t but was: <[1, 2, 2]>\
+                   \n\n\tunexpected items: <[2]>")]
+    fn should_panic_if_iterator_contains_extra_duplicate() {
+        let test_vec = vec![1, 2, 2];
+        assert_that(&test_vec.iter()).contains_exactly_in_any_order(&vec![&1, &2]);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_contains_only_expected_values() {
+        let test_vec = vec![1, 2, 2, 1];
+
+        assert_that(&test_vec).contains_only(&vec![&2, &1]);
+        assert_that(&test_vec.iter()).contains_only(&vec![&1, &2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to contain only <[1, 4]>\
+                   \n\t but was: <[1, 2, 1]>\
+                   \n\n\tmissing items: <[4]>\
+                   \n\tunexpected items: <[2]>")]
+    fn should_panic_if_vec_does_not_contain_only_expected_values() {
+        assert_that(&vec![1, 2, 1]).contains_only(&vec![&1, &4]);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_contains_none_of_values() {
+        let test_vec = vec![1, 2, 3];
+
+        assert_that(&test_vec).contains_none_of(&vec![&4, &5]);
+        assert_that(&test_vec.iter()).contains_none_of(&vec![&4, &5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to contain none of <[4, 2, 3]>\
+                   \n\t but was: <[1, 2, 3]>\
+                   \n\n\tunexpected items: <[2, 3]>")]
+    fn should_panic_if_iterator_contains_any_of_values() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec.iter()).contains_none_of(&vec![&4, &2, &3]);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_contains_sequence() {
+        let test_vec = vec![1, 2, 1, 2, 3, 4];
+        let empty_vec: Vec<&u8> = vec![];
+
+        assert_that(&test_vec).contains_sequence(&vec![&1, &2, &3]);
+        assert_that(&test_vec.iter()).contains_sequence(&vec![&3, &4]);
+        assert_that(&vec![1u8]).contains_sequence(&empty_vec);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to contain sequence <[1, 2, 3]>\
+                   \n\t but was: <[1, 2, 4, 1, 2]>\
+                   \n\n\tlongest partial match: <[1, 2]> at index <0>")]
+    fn should_panic_if_vec_does_not_contain_sequence() {
+        assert_that(&vec![1, 2, 4, 1, 2]).contains_sequence(&vec![&1, &2, &3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to contain sequence <[5, 6]>\
+                   \n\t but was: <[1, 2]>\
+                   \n\n\tno partial match")]
+    fn should_panic_if_iterator_does_not_contain_any_of_sequence() {
+        let test_vec = vec![1, 2];
+        assert_that(&test_vec.iter()).contains_sequence(&vec![&5, &6]);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_contains_subsequence() {
+        let test_vec = vec![1, 2, 3, 4];
+
+        assert_that(&test_vec).contains_subsequence(&vec![&1, &3, &4]);
+        assert_that(&test_vec.iter()).contains_subsequence(&vec![&2, &4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to contain subsequence <[1, 3, 2]>\
+                   \n\t but was: <[1, 2, 3, 4]>\
+                   \n\n\tlongest partial match: <[1, 3]> at indexes <[0, 2]>")]
+    fn should_panic_if_vec_does_not_contain_subsequence() {
+        assert_that(&vec![1, 2, 3, 4]).contains_subsequence(&vec![&1, &3, &2]);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_starts_and_ends_with_items() {
+        let test_vec = vec![1, 2, 3, 4];
+
+        assert_that(&test_vec).starts_with_items(&vec![&1, &2]);
+        assert_that(&test_vec).ends_with_items(&vec![&3, &4]);
+        assert_that(&test_vec.iter()).starts_with_items(&vec![&1]);
+        assert_that(&test_vec.iter()).ends_with_items(&vec![&1, &2, &3, &4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to start with items <[1, 2, 4]>\
+                   \n\t but was: <[1, 2, 3]>\
+                   \n\n\tlongest partial match: <[1, 2]>")]
+    fn should_panic_if_vec_does_not_start_with_items() {
+        assert_that(&vec![1, 2, 3]).starts_with_items(&vec![&1, &2, &4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to end with items <[1, 2, 3, 4]>\
+                   \n\t but was: <[2, 3, 4]>\
+                   \n\n\tlongest partial match: <[2, 3, 4]>")]
+    fn should_panic_if_iterator_does_not_end_with_items() {
+        let test_vec = vec![2, 3, 4];
+        assert_that(&test_vec.iter()).ends_with_items(&vec![&1, &2, &3, &4]);
+    }
+
+    #[test]
+    fn should_not_panic_if_all_items_satisfy_assertions() {
+        let test_vec = vec![TestStruct { value: 5 }, TestStruct { value: 6 }];
+
+        assert_that(&test_vec).all_satisfy(|val| {
+            assert_that(&val.value).is_greater_than(&4);
+            assert_that(&val.value).is_less_than(&7);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all items to satisfy the assertions\
+                   \n\t but was: <2> of <3> items did not\
+                   \n\n\titem at index <0>: <1>\
+                   \n\t\texpected: value greater than <1>\
+                   \n\t\t but was: <1>\
+                   \n\n\titem at index <2>: <0>\
+                   \n\t\texpected: value greater than <1>\
+                   \n\t\t but was: <0>\n")]
+    fn should_panic_with_each_failing_item_if_not_all_items_satisfy_assertions() {
+        assert_that(&vec![1, 2, 0]).all_satisfy(|val| {
+            assert_that(val).is_greater_than(&1);
+        });
+    }
+
+    #[test]
+    fn should_format_failing_items_with_thread_formatter() {
+        set_thread_formatter(CompactFormatter);
+        let result = check(|| {
+            assert_that(&vec![1, 0]).all_satisfy(|val| {
+                assert_that(val).is_greater_than(0);
+            });
+        });
+        reset_thread_formatter();
+
+        let context = result.unwrap_err().context;
+        assert_that(&context[0])
+            .is_equal_to("item at index <1>: <0> expected: value greater than <0> but was: <0>"
+                .to_string());
+    }
+
+    #[test]
+    fn should_not_panic_if_no_items_match() {
+        assert_that(&vec![1, 3, 5]).none_match(|val| val % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: no items to match\
+                   \n\t but was: <2> items matched in <[1, 2, 3, 4]>\
+                   \n\n\tmatching items:\
+                   \n\titem at index <1>: <2>\
+                   \n\titem at index <3>: <4>")]
+    fn should_panic_if_any_items_match() {
+        assert_that(&vec![1, 2, 3, 4]).none_match(|val| val % 2 == 0);
+    }
+
+    #[test]
+    fn should_not_panic_if_exactly_n_items_match() {
+        assert_that(&vec![1, 2, 3, 4]).exactly_n_match(2, |val| val % 2 == 0);
+        assert_that(&vec![1, 3]).exactly_n_match(0, |val| val % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <3> items to match\
+                   \n\t but was: <1> items matched in <[1, 2, 3]>\
+                   \n\n\tmatching items:\
+                   \n\titem at index <1>: <2>")]
+    fn should_panic_if_not_exactly_n_items_match() {
+        assert_that(&vec![1, 2, 3]).exactly_n_match(3, |val| val % 2 == 0);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_has_no_duplicates() {
+        let test_vec = vec![1, 2, 3];
+
+        assert_that(&test_vec).has_no_duplicates();
+        assert_that(&test_vec.iter()).has_no_duplicates();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to have no duplicates\
+                   \n\t but was: <[1, 2, 1, 3, 2, 1]>\
+                   \n\n\tduplicated items:\
+                   \n\t<1> at indexes <[0, 2, 5]>\
+                   \n\t<2> at indexes <[1, 4]>")]
+    fn should_panic_if_vec_has_duplicates() {
+        assert_that(&vec![1, 2, 1, 3, 2, 1]).has_no_duplicates();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to have no duplicates\
+                   \n\t but was: <[1.5, 2.5, 1.5]>\
+                   \n\n\tduplicated items:\
+                   \n\t<1.5> at indexes <[0, 2]>")]
+    fn should_panic_if_iterator_of_partial_eq_items_has_duplicates() {
+        let test_vec = vec![1.5, 2.5, 1.5];
+        assert_that(&test_vec.iter()).has_no_duplicates_by(|a, b| a == b);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_of_floats_has_no_duplicates() {
+        let test_vec = vec![1.5, 2.5, f64::NAN];
+
+        assert_that(&test_vec).has_no_duplicates();
+        assert_that(&test_vec.iter()).has_no_duplicates();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to have no duplicates\
+                   \n\t but was: <[1.5, 2.5, 1.5, 2.5]>\
+                   \n\n\tduplicated items:\
+                   \n\t<1.5> at indexes <[0, 2]>\
+                   \n\t<2.5> at indexes <[1, 3]>")]
+    fn should_panic_if_vec_of_floats_has_duplicates() {
+        let test_vec: Vec<f64> = vec![1.5, 2.5, 1.5, 2.5];
+        assert_that(&test_vec).has_no_duplicates();
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_of_partial_eq_items_has_no_duplicates() {
+        let test_vec = vec![1.5, 2.5];
+
+        assert_that(&test_vec).has_no_duplicates_by(|a, b| a == b);
+        assert_that(&test_vec.iter()).has_no_duplicates_by(|a, b| a == b);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to have no duplicates\
+                   \n\t but was: <[TestStruct { value: 5 }, TestStruct { value: 15 }]>\
+                   \n\n\tduplicated items:\
+                   \n\t<TestStruct { value: 5 }> at indexes <[0, 1]>")]
+    fn should_panic_if_vec_has_duplicates_by_provided_function() {
+        let test_vec = vec![TestStruct { value: 5 }, TestStruct { value: 15 }];
+        assert_that(&test_vec).has_no_duplicates_by(|a, b| a.value % 10 == b.value % 10);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_has_no_duplicate_keys() {
+        let test_vec = vec![TestStruct { value: 5 }, TestStruct { value: 6 }];
+
+        assert_that(&test_vec).has_no_duplicates_by_key(|val| val.value);
+        assert_that(&test_vec.iter()).has_no_duplicates_by_key(|val| val.value);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to have no duplicates\
+                   \n\t but was: <[TestStruct { value: 5 }, TestStruct { value: 15 }]>\
+                   \n\n\tduplicated keys:\
+                   \n\t<5> at indexes <[0, 1]>")]
+    fn should_panic_if_vec_has_duplicate_keys() {
+        let test_vec = vec![TestStruct { value: 5 }, TestStruct { value: 15 }];
+        assert_that(&test_vec).has_no_duplicates_by_key(|val| val.value % 10);
+    }
+
+    #[test]
+    fn should_return_single_item() {
+        let test_vec = vec![TestStruct { value: 5 }];
+        assert_that(&test_vec).single().map(|val| &val.value).is_equal_to(&5);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator to contain a single item\
+                   \n\t but was: <2> items in <[1, 2]>")]
+    fn should_panic_if_iterable_does_not_contain_single_item() {
+        assert_that(&vec![1, 2]).single();
+    }
+
+    #[test]
+    fn should_filter_items() {
+        let test_vec =
+            vec![TestStruct { value: 5 }, TestStruct { value: 6 }, TestStruct { value: 7 }];
+
+        assert_that(&test_vec).filtered_on(|val| val.value > 5).has_length(2);
+        assert_that(&test_vec).filtered_on(|val| val.value > 7).is_empty();
+        assert_that(&test_vec)
+            .filtered_on(|val| val.value < 6)
+            .single()
+            .map(|val| &val.value)
+            .is_equal_to(&5);
+    }
+
+    #[test]
+    fn should_extract_values_from_items() {
+        let names = vec!["alice".to_string(), "bob".to_string()];
+
+        assert_that(&names).extracting(|name| name.to_uppercase()).contains("BOB".to_string());
+        assert_that(&names).extracting(|name| name.len()).is_sorted_descending();
+    }
+
+    #[test]
+    fn should_extract_values_borrowing_from_items() {
+        let test_vec = vec![TestStruct { value: 5 }, TestStruct { value: 6 }];
+        let names = vec!["alice".to_string(), "bob".to_string()];
+
+        assert_that(&test_vec).extracting(|val| &val.value).contains(&6);
+        assert_that(&names).extracting(|name| name.as_str()).contains("bob");
+    }
+
+    #[test]
+    fn should_be_able_to_filter_and_extract_several_times() {
+        let test_vec = vec![TestStruct { value: 5 }, TestStruct { value: 6 }];
+        let mut spec = assert_that(&test_vec);
+
+        spec.filtered_on(|val| val.value > 5).has_length(1);
+        spec.extracting(|val| val.value).contains(5).contains(6);
+        spec.has_length(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [names]\
+                   \n\texpected: iterator to contain <\"carol\">\
+                   \n\t but was: <[\"alice\", \"bob\"]>")]
+    fn should_keep_subject_name_when_extracting_values() {
+        let names = vec!["alice".to_string(), "bob".to_string()];
+        assert_that(&names)
+            .named("names")
+            .extracting(|name| name.clone())
+            .contains("carol".to_string());
+    }
+
     #[derive(Debug, PartialEq)]
     struct TestStruct {
         pub value: u8,
@@ -140,10 +140,18 @@
 //! hopefully it's enough to start you off with writing assertions in your tests using Spectral.
 
 use std::borrow::Borrow;
+use std::cell::{Cell, RefCell};
 use std::cmp::PartialEq;
+use std::fmt;
 use std::fmt::Debug;
+use std::panic;
+use std::error::Error;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+
+use matcher::Matcher;
 
-use colours::{TERM_RED, TERM_BOLD, TERM_RESET};
 
 pub mod boolean;
 pub mod hashmap;
@@ -152,28 +160,28 @@ pub mod option;
 pub mod path;
 pub mod prelude;
 pub mod result;
+pub mod set;
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
 pub mod string;
 pub mod vec;
 pub mod iter;
+pub mod formatter;
+pub mod matcher;
 
-// Disable colours during tests, otherwise trying to assert on the panic message becomes
-// significantly more annoying.
-#[cfg(not(test))]
-mod colours {
-    pub const TERM_RED: &'static str = "\x1B[31m";
-    pub const TERM_BOLD: &'static str = "\x1B[1m";
-    pub const TERM_RESET: &'static str = "\x1B[0m";
-}
+mod colours;
+mod diff;
 
-#[cfg(test)]
-mod colours {
-    pub const TERM_RED: &'static str = "";
-    pub const TERM_BOLD: &'static str = "";
-    pub const TERM_RESET: &'static str = "";
-}
+pub use colours::{set_colour_choice, ColourChoice};
 
 #[cfg(feature = "num")]
 extern crate num;
+#[cfg(feature = "regex")]
+extern crate regex;
+#[cfg(feature = "unicode")]
+extern crate unicode_normalization;
+#[cfg(feature = "unicode")]
+extern crate unicode_width;
 
 #[macro_export]
 macro_rules! assert_that {
@@ -217,16 +225,59 @@ pub trait DescriptiveSpec<'r> {
     fn subject_name(&self) -> Option<&'r str>;
     fn location(&self) -> Option<String>;
     fn description(&self) -> Option<&'r str>;
+
+    /// Where the failures of this spec are reported. By default, this is decided when the
+    /// failure is raised, as described by `Reporter::current`.
+    fn reporter(&self) -> Reporter<'r> {
+        Reporter::current()
+    }
 }
 
 /// A failed assertion.
 ///
-/// This exposes builder methods to construct the final failure message.
+/// This exposes builder methods to construct the final failure message, and is the supported way
+/// for assertions outside of this crate to fail. Failures built this way are rendered, collected
+/// by `SoftAssertions` and returned by `check` exactly like those of the built-in assertions.
+///
+/// ```rust,ignore
+/// impl<'s> DateAssertions for Spec<'s, NaiveDate> {
+///     fn is_weekend(&mut self) -> &mut Self {
+///         let subject = self.subject();
+///
+///         if subject.weekday().number_from_monday() < 6 {
+///             AssertionFailure::from_spec(self)
+///                 .with_assertion_name("is_weekend")
+///                 .with_context(format!("{} is a {:?}", subject, subject.weekday()))
+///                 .fail_with_message(format!("expected <{}> to be a weekend", subject));
+///         }
+///
+///         self
+///     }
+/// }
+/// ```
 #[derive(Debug)]
 pub struct AssertionFailure<'r, T: 'r> {
     spec: &'r T,
+    assertion_name: Option<&'static str>,
     expected: Option<String>,
     actual: Option<String>,
+    context: Vec<String>,
+}
+
+/// The details of a failed assertion.
+///
+/// This is returned by `check`, and is the panic payload when structured panics are enabled.
+/// Its `Display` implementation renders the same message that a failing assertion panics with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssertionError {
+    pub assertion_name: Option<&'static str>,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub message: Option<String>,
+    pub description: Option<String>,
+    pub subject_name: Option<String>,
+    pub location: Option<String>,
+    pub context: Vec<String>,
 }
 
 /// A description for an assertion.
@@ -236,18 +287,84 @@ pub struct AssertionFailure<'r, T: 'r> {
 pub struct SpecDescription<'r> {
     value: &'r str,
     location: Option<String>,
+    reporter: Reporter<'r>,
 }
 
 /// An assertion.
 ///
 /// This is created by either the `assert_that` function, or by calling `that` on a
-/// `SpecDescription`.
+/// `SpecDescription`. A `Spec` for a value taken from the subject of another `Spec` is created
+/// with `derive`. Some of the fields are private, so a `Spec` cannot be created with a struct
+/// literal.
 #[derive(Debug)]
 pub struct Spec<'s, S: 's> {
-    pub subject: &'s S,
+    subject: Subject<'s, S>,
     pub subject_name: Option<&'s str>,
     pub location: Option<String>,
     pub description: Option<&'s str>,
+    reporter: Reporter<'s>,
+}
+
+/// The subject of a `Spec`, which is either borrowed from the test or owned by the `Spec`, such
+/// as a value computed by `map_owned`.
+#[derive(Debug)]
+enum Subject<'s, S: 's> {
+    Borrowed(&'s S),
+    Owned(S),
+}
+
+/// Collects failed assertions instead of panicking on the first one.
+///
+/// Every failure of a `Spec` created by `that` or `asserting` is recorded, and all of them are
+/// reported together in a single panic when `assert_all` is called or when the collector is
+/// dropped.
+///
+/// ```rust,ignore
+/// let soft = SoftAssertions::new();
+/// soft.that(&response.status).is_equal_to(&200);
+/// soft.that(&response.body).contains(&"Hello");
+/// soft.assert_all();
+/// ```
+#[derive(Debug, Default)]
+pub struct SoftAssertions {
+    failures: Mutex<Vec<AssertionError>>,
+}
+
+/// Where the failures of a `Spec` are sent: to a panic, to `SoftAssertions`, or back to an
+/// enclosing `check`.
+///
+/// This is decided when the `Spec` is created, so a `Spec` keeps reporting to the same place even
+/// if it is handed to another thread.
+#[derive(Debug, Clone)]
+pub struct Reporter<'r> {
+    target: ReportTarget<'r>,
+}
+
+#[derive(Debug, Clone)]
+enum ReportTarget<'r> {
+    Panic,
+    Soft(&'r SoftAssertions),
+    Check(Arc<CheckedFailure>),
+}
+
+/// The first failure raised within a `check`, which is handed back once the closure returns.
+#[derive(Debug, Default)]
+struct CheckedFailure {
+    state: Mutex<CheckState>,
+}
+
+#[derive(Debug, Default)]
+struct CheckState {
+    error: Option<AssertionError>,
+    finished: bool,
+}
+
+/// The payload used to leave the closure of a `check` after a failure it cannot carry on from.
+struct CheckAborted(Arc<CheckedFailure>);
+
+thread_local! {
+    static CHECKS: RefCell<Vec<Arc<CheckedFailure>>> = const { RefCell::new(Vec::new()) };
+    static STRUCTURED_PANICS: Cell<bool> = const { Cell::new(false) };
 }
 
 /// Wraps a subject in a `Spec` to provide assertions against it.
@@ -255,10 +372,11 @@ pub struct Spec<'s, S: 's> {
 /// The subject must be a reference.
 pub fn assert_that<'s, S>(subject: &'s S) -> Spec<'s, S> {
     Spec {
-        subject: subject,
+        subject: Subject::Borrowed(subject),
         subject_name: None,
         location: None,
         description: None,
+        reporter: Reporter::current(),
     }
 }
 
@@ -267,7 +385,219 @@ pub fn asserting(description: &str) -> SpecDescription {
     SpecDescription {
         value: description,
         location: None,
+        reporter: Reporter::current(),
+    }
+}
+
+/// Runs the provided assertions, returning the first failure as an `AssertionError` rather than
+/// panicking, or the value returned by the closure if they all pass.
+///
+/// Failures of a `Spec` created within the closure are collected rather than raised, so the
+/// remaining assertions still run and the panic hook is not called. Assertions which cannot carry
+/// on past a failure, such as `is_some` handing back a `Spec` for a missing value, leave the
+/// closure by unwinding, so with `panic = "abort"` these will still abort the process.
+///
+/// Any other panic raised by the closure is passed through. Failures of a `Spec` handed out by
+/// `SoftAssertions` are still collected there.
+///
+/// ```rust,ignore
+/// let result = check(|| {
+///     assert_that(&1).is_equal_to(&2);
+/// });
+///
+/// assert_that(&result).is_err();
+/// ```
+pub fn check<F, R>(assertions: F) -> Result<R, Box<AssertionError>>
+    where F: FnOnce() -> R
+{
+    let checked = Arc::new(CheckedFailure::default());
+
+    CHECKS.with(|checks| checks.borrow_mut().push(checked.clone()));
+    let result = panic::catch_unwind(AssertUnwindSafe(assertions));
+    CHECKS.with(|checks| checks.borrow_mut().pop());
+
+    let error = checked.finish();
+
+    match result {
+        Ok(value) => {
+            match error {
+                Some(error) => Err(Box::new(error)),
+                None => Ok(value),
+            }
+        }
+        Err(payload) => {
+            let aborted = match payload.downcast::<CheckAborted>() {
+                Ok(aborted) => aborted,
+                Err(payload) => panic::resume_unwind(payload),
+            };
+
+            match error {
+                Some(error) if Arc::ptr_eq(&aborted.0, &checked) => Err(Box::new(error)),
+                _ => panic::resume_unwind(aborted),
+            }
+        }
+    }
+}
+
+/// Sets whether assertions failing on the current thread panic with their `AssertionError` as
+/// the payload (using `panic_any`) instead of the rendered message.
+///
+/// This allows a custom panic hook or test harness to downcast the payload and render or
+/// serialize the failure itself. Note that `#[should_panic(expected = "...")]` only matches
+/// message payloads, and the default panic hook will not print the failure. As the setting only
+/// applies to the current thread, other tests running in parallel are not affected.
+pub fn set_structured_panics(enabled: bool) {
+    STRUCTURED_PANICS.with(|structured| structured.set(enabled));
+}
+
+impl SoftAssertions {
+    pub fn new() -> SoftAssertions {
+        SoftAssertions { failures: Mutex::new(vec![]) }
+    }
+
+    /// Wraps a subject in a `Spec` whose failures are collected rather than panicking.
+    pub fn that<'s, S>(&'s self, subject: &'s S) -> Spec<'s, S> {
+        Spec {
+            subject: Subject::Borrowed(subject),
+            subject_name: None,
+            location: None,
+            description: None,
+            reporter: Reporter { target: ReportTarget::Soft(self) },
+        }
+    }
+
+    /// Describes an assertion whose failures are collected rather than panicking.
+    pub fn asserting<'s>(&'s self, description: &'s str) -> SpecDescription<'s> {
+        SpecDescription {
+            value: description,
+            location: None,
+            reporter: Reporter { target: ReportTarget::Soft(self) },
+        }
+    }
+
+    /// Calls `panic` with every collected failure, if there are any.
+    pub fn assert_all(self) {
+        self.panic_if_failed();
+    }
+
+    fn record(&self, error: AssertionError) {
+        self.failures().push(error);
+    }
+
+    fn panic_if_failed(&self) {
+        let failures: Vec<AssertionError> = self.failures().drain(..).collect();
+
+        if !failures.is_empty() {
+            panic!("{}", build_soft_failure_message(&failures));
+        }
+    }
+
+    fn failures(&self) -> MutexGuard<'_, Vec<AssertionError>> {
+        self.failures.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for SoftAssertions {
+    fn drop(&mut self) {
+        if !thread::panicking() {
+            self.panic_if_failed();
+        }
+    }
+}
+
+impl<'r> Reporter<'r> {
+    /// Reports to the innermost `check` running on the current thread, or panics if there is
+    /// none.
+    pub fn current() -> Reporter<'r> {
+        let target = CHECKS.with(|checks| match checks.borrow().last() {
+            Some(checked) => ReportTarget::Check(checked.clone()),
+            None => ReportTarget::Panic,
+        });
+
+        Reporter { target }
     }
+
+    fn report(&self, error: AssertionError) {
+        let error = match self.target {
+            ReportTarget::Panic => error,
+            ReportTarget::Soft(soft_assertions) => return soft_assertions.record(error),
+            ReportTarget::Check(ref checked) => {
+                match checked.record(error) {
+                    Some(error) => error,
+                    None => return,
+                }
+            }
+        };
+
+        if STRUCTURED_PANICS.with(|structured| structured.get()) {
+            panic::panic_any(error);
+        }
+
+        panic!("{}", formatter::format_failure(&error));
+    }
+
+    /// Leaves the assertion after a failure that it cannot carry on from has been reported.
+    fn abort(&self) -> ! {
+        match self.target {
+            ReportTarget::Panic => {}
+            ReportTarget::Soft(soft_assertions) => soft_assertions.panic_if_failed(),
+            ReportTarget::Check(ref checked) => {
+                if !checked.state().finished {
+                    panic::resume_unwind(Box::new(CheckAborted(checked.clone())));
+                }
+            }
+        }
+
+        unreachable!();
+    }
+}
+
+impl CheckedFailure {
+    /// Keeps the failure if it is the first one, or hands it back if the `check` has already
+    /// returned.
+    fn record(&self, error: AssertionError) -> Option<AssertionError> {
+        let mut state = self.state();
+
+        if state.finished {
+            return Some(error);
+        }
+
+        if state.error.is_none() {
+            state.error = Some(error);
+        }
+
+        None
+    }
+
+    fn finish(&self) -> Option<AssertionError> {
+        let mut state = self.state();
+        state.finished = true;
+        state.error.take()
+    }
+
+    fn state(&self) -> MutexGuard<'_, CheckState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn build_soft_failure_message(failures: &[AssertionError]) -> String {
+    let plural = if failures.len() == 1 { "" } else { "s" };
+    let messages: Vec<String> = failures.iter()
+        .map(|failure| {
+            let mut message = formatter::format_failure(failure);
+            if !message.ends_with('\n') {
+                message.push('\n');
+            }
+            message
+        })
+        .collect();
+
+    format!("\n\t{}{} soft assertion failure{}:{}\n{}",
+            colours::bold(),
+            failures.len(),
+            plural,
+            colours::reset(),
+            messages.concat())
 }
 
 impl<'r> SpecDescription<'r> {
@@ -281,10 +611,11 @@ impl<'r> SpecDescription<'r> {
     /// Creates a new assertion, passing through its description.
     pub fn that<S>(self, subject: &'r S) -> Spec<'r, S> {
         Spec {
-            subject: subject,
+            subject: Subject::Borrowed(subject),
             subject_name: None,
             location: self.location,
             description: Some(self.value),
+            reporter: self.reporter,
         }
     }
 }
@@ -301,6 +632,10 @@ impl<'r, T> DescriptiveSpec<'r> for Spec<'r, T> {
     fn description(&self) -> Option<&'r str> {
         self.description
     }
+
+    fn reporter(&self) -> Reporter<'r> {
+        self.reporter.clone()
+    }
 }
 
 impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
@@ -308,87 +643,166 @@ impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
     pub fn from_spec(spec: &'r T) -> AssertionFailure<'r, T> {
         AssertionFailure {
             spec: spec,
+            assertion_name: None,
             expected: None,
             actual: None,
+            context: vec![],
         }
     }
 
+    /// Builder method to add the name of the failed assertion, such as `"is_equal_to"`.
+    pub fn with_assertion_name(&mut self, assertion_name: &'static str) -> &mut Self {
+        self.assertion_name = Some(assertion_name);
+        self
+    }
+
     /// Builder method to add the expected value for the panic message.
     pub fn with_expected(&mut self, expected: String) -> &mut Self {
-        let mut assertion = self;
-        assertion.expected = Some(expected);
-
-        assertion
+        self.expected = Some(expected);
+        self
     }
 
     /// Builder method to add the actual value for the panic message.
     pub fn with_actual(&mut self, actual: String) -> &mut Self {
-        let mut assertion = self;
-        assertion.actual = Some(actual);
+        self.actual = Some(actual);
+        self
+    }
 
-        assertion
+    /// Builder method to add further detail, such as a diff, to the end of the panic message.
+    ///
+    /// This can be called multiple times, with each context shown as a separate paragraph.
+    pub fn with_context(&mut self, context: String) -> &mut Self {
+        self.context.push(context);
+        self
     }
 
     /// Builds the failure message with a description (if present), the expected value,
     /// and the actual value and then calls `panic` with the created message.
+    ///
+    /// If the spec belongs to `SoftAssertions`, the failure is recorded instead. Within `check`,
+    /// the failure is handed back as an `AssertionError`.
     pub fn fail(&mut self) {
-        if !self.expected.is_some() || !self.actual.is_some() {
+        if self.expected.is_none() || self.actual.is_none() {
             panic!("invalid assertion");
         }
 
-        let location = self.maybe_build_location();
-        let subject_name = self.maybe_build_subject_name();
-        let description = self.maybe_build_description();
+        let error = self.build_error(None);
+        self.report(error);
+    }
+
+    /// Behaves like `fail`, but never returns.
+    ///
+    /// This is for assertions which cannot carry on past a failure, such as those handing back a
+    /// `Spec` for a value that is not present. Within `SoftAssertions`, every failure collected so
+    /// far is reported along with this one.
+    pub fn fail_fatal(&mut self) -> ! {
+        self.fail();
 
-        panic!(format!("{}{}\n\t{}expected: {}\n\t but was: {}{}\n{}",
-                       description,
-                       subject_name,
-                       TERM_RED,
-                       self.expected.clone().unwrap(),
-                       self.actual.clone().unwrap(),
-                       TERM_RESET,
-                       location))
+        self.spec.reporter().abort()
+    }
+
+    /// Calls `panic` with the provided message in place of the expected and actual values,
+    /// prepending the assertion description if present.
+    ///
+    /// Like `fail`, the failure is recorded instead when the spec belongs to `SoftAssertions`,
+    /// and handed back as an `AssertionError` within `check`.
+    pub fn fail_with_message(&mut self, message: String) {
+        let error = self.build_error(Some(message));
+        self.report(error);
     }
 
-    /// Calls `panic` with the provided message, prepending the assertion description
-    /// if present.
-    fn fail_with_message(&mut self, message: String) {
-        let location = self.maybe_build_location();
-        let subject_name = self.maybe_build_subject_name();
-        let description = self.maybe_build_description();
+    /// Behaves like `fail_with_message`, but never returns. See `fail_fatal`.
+    pub fn fail_fatal_with_message(&mut self, message: String) -> ! {
+        self.fail_with_message(message);
 
-        panic!(format!("{}{}\n\t{}{}{}\n{}",
-                       description,
-                       subject_name,
-                       TERM_RED,
-                       message,
-                       TERM_RESET,
-                       location))
+        self.spec.reporter().abort()
     }
 
+    fn build_error(&self, message: Option<String>) -> AssertionError {
+        AssertionError {
+            assertion_name: self.assertion_name,
+            expected: self.expected.clone(),
+            actual: self.actual.clone(),
+            message: message,
+            description: self.spec.description().map(String::from),
+            subject_name: self.spec.subject_name().map(String::from),
+            location: self.spec.location(),
+            context: self.context.clone(),
+        }
+    }
+
+    fn report(&self, error: AssertionError) {
+        self.spec.reporter().report(error);
+    }
+}
+
+impl fmt::Display for AssertionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "{}{}",
+               self.maybe_build_description(),
+               self.maybe_build_subject_name())?;
+
+        match self.message {
+            Some(ref message) => {
+                write!(f, "\n\t{}{}{}", colours::red(), message, colours::reset())?
+            }
+            None => {
+                write!(f,
+                       "\n\t{}expected: {}\n\t but was: {}{}",
+                       colours::red(),
+                       self.expected.clone().unwrap_or_default(),
+                       self.actual.clone().unwrap_or_default(),
+                       colours::reset())?
+            }
+        }
+
+        for context in &self.context {
+            write!(f, "\n\n\t{}", context.replace("\n", "\n\t"))?;
+        }
+
+        write!(f, "\n{}", self.maybe_build_location())
+    }
+}
+
+impl Error for AssertionError {}
+
+impl AssertionError {
     fn maybe_build_location(&self) -> String {
-        match self.spec.location() {
-            Some(value) => format!("\n\t{}at location: {}{}\n", TERM_BOLD, value, TERM_RESET),
+        match self.location {
+            Some(ref value) => {
+                format!("\n\t{}at location: {}{}\n", colours::bold(), value, colours::reset())
+            }
             None => "".to_string(),
         }
     }
 
     fn maybe_build_description(&self) -> String {
-        match self.spec.description() {
-            Some(value) => format!("\n\t{}{}:{}", TERM_BOLD, value, TERM_RESET),
+        match self.description {
+            Some(ref value) => format!("\n\t{}{}:{}", colours::bold(), value, colours::reset()),
             None => "".to_string(),
         }
     }
 
     fn maybe_build_subject_name(&self) -> String {
-        match self.spec.subject_name() {
-            Some(value) => format!("\n\t{}for subject [{}]{}", TERM_BOLD, value, TERM_RESET),
+        match self.subject_name {
+            Some(ref value) => {
+                format!("\n\t{}for subject [{}]{}", colours::bold(), value, colours::reset())
+            }
             None => "".to_string(),
         }
     }
 }
 
 impl<'s, S> Spec<'s, S> {
+    /// The subject of the assertions, whether it is borrowed or owned by this `Spec`.
+    pub fn subject(&self) -> &S {
+        match self.subject {
+            Subject::Borrowed(subject) => subject,
+            Subject::Owned(ref subject) => subject,
+        }
+    }
+
     /// Provides the actual location of the assertion.
     ///
     /// Usually you would not call this directly, but use the macro forms of `assert_that` and
@@ -409,6 +823,81 @@ impl<'s, S> Spec<'s, S> {
 
         spec
     }
+
+    /// Asserts that the subject satisfies the provided `Matcher`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&response).satisfies(HasStatus(404));
+    /// ```
+    pub fn satisfies<M: Matcher<S>>(&mut self, matcher: M) -> &mut Self {
+        self.check_matcher("satisfies", &matcher);
+
+        self
+    }
+
+    /// Asserts that the subject satisfies the provided `Matcher`. This is an alias of
+    /// `satisfies`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&5).is(equal_to(5));
+    /// ```
+    pub fn is<M: Matcher<S>>(&mut self, matcher: M) -> &mut Self {
+        self.check_matcher("is", &matcher);
+
+        self
+    }
+
+    /// Creates a `Spec` for a value borrowed from the subject, keeping the subject name,
+    /// location, description and soft assertions of this `Spec`.
+    ///
+    /// This is intended for custom assertions which return a new `Spec`, as a `Spec` can no
+    /// longer be created with a struct literal.
+    ///
+    /// ```rust,ignore
+    /// fn has_header(&mut self, name: &str) -> Spec<'_, String> {
+    ///     match self.subject().headers.get(name) {
+    ///         Some(value) => self.derive(value),
+    ///         None => {
+    ///             AssertionFailure::from_spec(self)
+    ///                 .with_expected(format!("response with header <{}>", name))
+    ///                 .with_actual("response without it".to_string())
+    ///                 .fail_fatal()
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn derive<'a, T>(&'a self, subject: &'a T) -> Spec<'a, T> {
+        self.derive_subject(Subject::Borrowed(subject))
+    }
+
+    /// Creates a `Spec` which owns a value computed from the subject, such as a collection of
+    /// extracted fields, keeping the subject name, location, description and soft assertions of
+    /// this `Spec`.
+    fn derive_owned<'a, T: 'a>(&'a self, subject: T) -> Spec<'a, T> {
+        self.derive_subject(Subject::Owned(subject))
+    }
+
+    fn derive_subject<'a, T: 'a>(&'a self, subject: Subject<'a, T>) -> Spec<'a, T> {
+        Spec {
+            subject,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+            reporter: self.reporter.clone(),
+        }
+    }
+
+    fn check_matcher<M: Matcher<S>>(&mut self, assertion_name: &'static str, matcher: &M) {
+        let subject = self.subject();
+
+        if !matcher.matches(subject) {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name(assertion_name)
+                .with_expected(matcher.description())
+                .with_actual(matcher.describe_mismatch(subject))
+                .fail();
+        }
+    }
 }
 
 impl<'s, S> Spec<'s, S>
@@ -420,16 +909,24 @@ impl<'s, S> Spec<'s, S>
     /// ```rust,ignore
     /// assert_that(&"hello").is_equal_to(&"hello");
     /// ```
-    pub fn is_equal_to<E: Borrow<S>>(&mut self, expected: E) {
-        let subject = self.subject;
+    pub fn is_equal_to<E: Borrow<S>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject();
         let borrowed_expected = expected.borrow();
 
         if !subject.eq(borrowed_expected) {
-            AssertionFailure::from_spec(self)
+            let mut failure = AssertionFailure::from_spec(self);
+            failure.with_assertion_name("is_equal_to")
                 .with_expected(format!("<{:?}>", borrowed_expected))
-                .with_actual(format!("<{:?}>", subject))
-                .fail();
+                .with_actual(format!("<{:?}>", subject));
+
+            for difference in diff::describe_difference(borrowed_expected, subject) {
+                failure.with_context(difference);
+            }
+
+            failure.fail();
         }
+
+        self
     }
 
     /// Asserts that the actual value and the expected value are not equal. The value type must
@@ -438,16 +935,19 @@ impl<'s, S> Spec<'s, S>
     /// ```rust,ignore
     /// assert_that(&"hello").is_not_equal_to(&"hello");
     /// ```
-    pub fn is_not_equal_to<E: Borrow<S>>(&mut self, expected: E) {
-        let subject = self.subject;
+    pub fn is_not_equal_to<E: Borrow<S>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject();
         let borrowed_expected = expected.borrow();
 
         if subject.eq(borrowed_expected) {
             AssertionFailure::from_spec(self)
+                .with_assertion_name("is_not_equal_to")
                 .with_expected(format!("<{:?}> to not equal <{:?}>", subject, borrowed_expected))
-                .with_actual(format!("equal"))
+                .with_actual("equal".to_string())
                 .fail();
         }
+
+        self
     }
 }
 
@@ -463,15 +963,18 @@ impl<'s, S> Spec<'s, S>
     /// ```rust,ignore
     /// assert_that(&"hello").matches(|x| x.eq(&"hello"));
     /// ```
-    pub fn matches<F>(&mut self, matching_function: F)
-        where F: Fn(&'s S) -> bool
+    pub fn matches<F>(&mut self, matching_function: F) -> &mut Self
+        where F: Fn(&S) -> bool
     {
-        let subject = self.subject;
+        let subject = self.subject();
 
         if !matching_function(subject) {
             AssertionFailure::from_spec(self)
+                .with_assertion_name("matches")
                 .fail_with_message(format!("expectation failed for value <{:?}>", subject));
         }
+
+        self
     }
 
     /// Transforms the subject of the `Spec` by passing it through to the provided mapping
@@ -481,15 +984,27 @@ impl<'s, S> Spec<'s, S>
     /// let test_struct = TestStruct { value: 5 };
     /// assert_that(&test_struct).map(|val| &val.value).is_equal_to(&5);
     /// ```
-    pub fn map<F, T>(self, mapping_function: F) -> Spec<'s, T>
-        where F: Fn(&'s S) -> &'s T
+    pub fn map<'a, F, T>(&'a mut self, mapping_function: F) -> Spec<'a, T>
+        where F: FnOnce(&'a S) -> &'a T
     {
-        Spec {
-            subject: mapping_function(self.subject),
-            subject_name: self.subject_name,
-            location: self.location.clone(),
-            description: self.description,
-        }
+        let spec: &'a Self = self;
+        spec.derive(mapping_function(spec.subject()))
+    }
+
+    /// Transforms the subject of the `Spec` by passing it through to the provided mapping
+    /// function, which unlike `map` can return a computed value rather than a reference.
+    ///
+    /// The computed value is owned by the new `Spec`, and may borrow from the subject.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"hello").map_owned(|val| val.len()).is_equal_to(&5);
+    /// assert_that(&name).map_owned(|val| val.as_str()).is_equal_to("bob");
+    /// ```
+    pub fn map_owned<'a, F, T: 'a>(&'a mut self, mapping_function: F) -> Spec<'a, T>
+        where F: FnOnce(&'a S) -> T
+    {
+        let spec: &'a Self = self;
+        spec.derive_owned(mapping_function(spec.subject()))
     }
 }
 
@@ -497,6 +1012,14 @@ impl<'s, S> Spec<'s, S>
 mod tests {
 
     use super::prelude::*;
+    use super::{set_structured_panics, AssertionError, AssertionFailure, Spec};
+
+    use std::cell::RefCell;
+    use std::error::Error;
+    use std::panic;
+    use std::rc::Rc;
+    use std::sync::Arc;
+    use std::thread;
 
     #[test]
     fn should_be_able_to_use_macro_form_with_deliberate_reference() {
@@ -606,6 +1129,27 @@ mod tests {
         assert_that(&1).is_equal_to(&2);
     }
 
+    #[test]
+    #[should_panic(expected = "\n\texpected: <\"one\\ntwo\\nthree\">\
+                   \n\t but was: <\"one\\n2\\nthree\">\
+                   \n\n\tdiff (- expected, + actual):\n\t@@ -1,3 +1,3 @@\
+                   \n\t  one\n\t- two\n\t+ 2\n\t  three\n")]
+    fn should_show_diff_if_multi_line_strings_are_unequal() {
+        let value = "one\n2\nthree".to_string();
+        assert_that(&value).is_equal_to("one\ntwo\nthree".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <Some(TestStruct { value: 2 })>\
+                   \n\t but was: <Some(TestStruct { value: 1 })>\
+                   \n\n\tfirst difference at: .0.value\
+                   \n\n\tdiff (- expected, + actual):\n\t@@ -1,5 +1,5 @@\
+                   \n\t  Some(\n\t      TestStruct {\
+                   \n\t-         value: 2,\n\t+         value: 1,")]
+    fn should_show_path_to_first_difference_if_structs_are_unequal() {
+        assert_that(&Some(TestStruct { value: 1 })).is_equal_to(Some(TestStruct { value: 2 }));
+    }
+
     #[test]
     fn is_not_equal_to_should_support_multiple_borrow_forms() {
         assert_that(&1).is_not_equal_to(2);
@@ -643,6 +1187,304 @@ mod tests {
         assert_that(&test_struct).map(|val| &val.value).is_equal_to(&5);
     }
 
+    #[test]
+    fn should_not_panic_if_all_soft_assertions_pass() {
+        let soft = SoftAssertions::new();
+        soft.that(&1).is_equal_to(&1);
+        soft.that(&"Hello").starts_with(&"H");
+        soft.assert_all();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\t2 soft assertion failures:\n\n\texpected: <2>\n\t but was: <1>\n\
+                   \n\texpected: string starting with <\"A\">\n\t but was: <\"Hello\">")]
+    fn should_panic_with_all_failures_on_assert_all() {
+        let soft = SoftAssertions::new();
+        soft.that(&1).is_equal_to(&2);
+        soft.that(&"Hello").starts_with(&"H");
+        soft.that(&"Hello").starts_with(&"A");
+        soft.assert_all();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\t1 soft assertion failure:\n\n\ttest condition:\
+                   \n\texpected: <2>\n\t but was: <1>")]
+    fn should_panic_with_collected_failures_when_soft_assertions_are_dropped() {
+        let soft = SoftAssertions::new();
+        soft.asserting(&"test condition").that(&1).is_equal_to(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\t2 soft assertion failures:\n\n\texpected: <2>\n\t but was: <1>\n\
+                   \n\texpected: option[some]\n\t but was: option[none]")]
+    fn should_report_collected_failures_if_soft_assertion_cannot_continue() {
+        let soft = SoftAssertions::new();
+        let option: Option<u8> = None;
+
+        soft.that(&1).is_equal_to(&2);
+        soft.that(&option).is_some().is_equal_to(&1);
+        soft.that(&3).is_equal_to(&4);
+    }
+
+    #[test]
+    fn should_return_ok_if_checked_assertions_pass() {
+        let result = check(|| {
+            assert_that(&1).is_equal_to(&1);
+            assert_that(&"Hello").starts_with(&"H");
+        });
+
+        assert_that(&result).is_ok();
+    }
+
+    #[test]
+    fn should_return_assertion_error_if_checked_assertion_fails() {
+        let result = check(|| {
+            asserting(&"test condition").that(&1).named(&"number one").is_equal_to(&2);
+        });
+
+        let error = AssertionError {
+            assertion_name: Some("is_equal_to"),
+            expected: Some("<2>".to_string()),
+            actual: Some("<1>".to_string()),
+            message: None,
+            description: Some("test condition".to_string()),
+            subject_name: Some("number one".to_string()),
+            location: None,
+            context: vec![],
+        };
+
+        assert_that(&result).is_err_containing(Box::new(error));
+    }
+
+    #[test]
+    fn should_return_first_failure_when_checking_assertions() {
+        let result = check(|| {
+            assert_that(&"Hello").starts_with(&"A");
+            assert_that(&1).is_equal_to(&2);
+        });
+
+        assert_that(&result)
+            .is_err()
+            .map(|err| &err.expected)
+            .is_equal_to(Some("string starting with <\"A\">".to_string()));
+    }
+
+    #[test]
+    fn should_render_checked_assertion_error_like_panic_message() {
+        let result = check(|| {
+            let value = "Hello";
+            assert_that!(&value).matches(|val| val.eq(&"Hi"));
+        });
+
+        let message = result.unwrap_err().to_string();
+        assert_that(&message).starts_with(&"\n\texpectation failed for value <\"Hello\">\
+                                            \n\n\tat location: src/lib.rs:");
+    }
+
+    #[test]
+    fn should_be_able_to_use_assertion_error_as_error() {
+        let result = check(|| {
+            assert_that(&true).is_false();
+        });
+
+        let error: Box<dyn Error> = result.unwrap_err();
+        assert_that(&error.to_string()).contains(&"expected: bool to be <false>");
+    }
+
+    #[test]
+    fn should_return_value_of_closure_if_checked_assertions_pass() {
+        let result = check(|| {
+            assert_that(&1).is_equal_to(1);
+            2
+        });
+
+        assert_that(&result).is_ok_containing(2);
+    }
+
+    #[test]
+    fn should_return_assertion_error_if_checked_assertion_cannot_continue() {
+        let option: Option<u8> = None;
+
+        let result = check(|| {
+            assert_that(&option).is_some().is_equal_to(1);
+        });
+
+        assert_that(&result)
+            .is_err()
+            .map(|err| &err.expected)
+            .is_equal_to(Some("option[some]".to_string()));
+    }
+
+    #[test]
+    fn should_return_assertion_error_raised_on_another_thread() {
+        let result = check(|| {
+            let mut spec = assert_that(&1);
+            thread::spawn(move || {
+                    spec.is_equal_to(2);
+                })
+                .join()
+                .unwrap();
+        });
+
+        assert_that(&result).is_err().map(|err| &err.actual).is_equal_to(Some("<1>".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2>\n\t but was: <1>")]
+    fn should_panic_if_spec_is_used_after_check_returns() {
+        let mut spec = None;
+
+        let _ = check(|| {
+            spec = Some(assert_that(&1));
+        });
+
+        spec.unwrap().is_equal_to(2);
+    }
+
+    #[test]
+    fn should_panic_with_assertion_error_if_structured_panics_are_enabled() {
+        set_structured_panics(true);
+        let result = panic::catch_unwind(|| {
+            assert_that(&1).is_equal_to(2);
+        });
+        set_structured_panics(false);
+
+        let error = result.unwrap_err().downcast::<AssertionError>().unwrap();
+
+        assert_that(&error.assertion_name).is_equal_to(Some("is_equal_to"));
+        assert_that(&error.expected).is_equal_to(Some("<2>".to_string()));
+        assert_that(&error.actual).is_equal_to(Some("<1>".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2>\n\t but was: <1>")]
+    fn should_only_enable_structured_panics_for_current_thread() {
+        thread::spawn(|| set_structured_panics(true)).join().unwrap();
+
+        assert_that(&1).is_equal_to(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "not an assertion")]
+    fn should_pass_through_other_panics_when_checking_assertions() {
+        let _ = check(|| panic!("not an assertion"));
+    }
+
+    fn parse_numbers(value: &str) -> Result<Vec<u32>, String> {
+        value.split(',')
+            .map(|number| number.trim().parse().map_err(|_| value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn should_be_able_to_use_macro_form_with_temporary_subject() {
+        assert_that!(parse_numbers("1, 2")).is_ok().has_length(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: result[ok]\n\t but was: result[error]<\"1, x\">\
+                   \n\n\tat location: src/lib.rs:")]
+    fn should_contain_file_and_line_for_temporary_subject() {
+        assert_that!(parse_numbers("1, x")).is_ok();
+    }
+
+    #[test]
+    fn should_be_able_to_map_to_owned_value() {
+        let test_struct = TestStruct { value: 5 };
+
+        assert_that(&"hello").map_owned(|val| val.len()).is_equal_to(5);
+        assert_that(&test_struct).map_owned(|val| val.value * 2).is_equal_to(10);
+    }
+
+    #[test]
+    fn should_be_able_to_map_to_owned_value_several_times() {
+        let mut spec = assert_that(&"hello");
+
+        spec.map_owned(|val| val.len()).is_equal_to(5);
+        spec.map_owned(|val| val.to_uppercase()).is_equal_to("HELLO".to_string());
+    }
+
+    #[test]
+    fn should_be_able_to_map_to_value_borrowing_from_subject() {
+        let name = "bob".to_string();
+
+        assert_that(&name).map_owned(|val| val.as_str()).is_equal_to("bob");
+    }
+
+    #[test]
+    fn should_be_able_to_map_to_value_which_is_not_send_or_sync() {
+        let value = Rc::new(RefCell::new(5));
+
+        assert_that(&value).map_owned(|val| *val.borrow()).is_equal_to(5);
+        assert_that(&0).map_owned(|_| value.clone()).map_owned(|val| *val.borrow()).is_equal_to(5);
+    }
+
+    #[test]
+    fn should_drop_owned_value_with_spec() {
+        let value = Arc::new(5);
+
+        {
+            let mut spec = assert_that(&0);
+            let mut owned = spec.map_owned(|_| value.clone());
+            owned.is_equal_to(Arc::new(5));
+            assert_eq!(Arc::strong_count(&value), 2);
+        }
+
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tpair:\n\tfor subject [first]\n\texpected: <2>\n\t but was: <1>")]
+    fn should_keep_description_and_subject_name_in_derived_spec() {
+        let pair = (1, "one");
+        asserting(&"pair").that(&pair).named("first").derive(&pair.0).is_equal_to(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [word]\n\texpected: <4>\n\t but was: <5>")]
+    fn should_keep_subject_name_when_mapping_to_owned_value() {
+        assert_that(&"hello").named("word").map_owned(|val| val.len()).is_equal_to(4);
+    }
+
+    trait EvenAssertions {
+        fn is_even(&mut self) -> &mut Self;
+    }
+
+    impl<'s> EvenAssertions for Spec<'s, u32> {
+        fn is_even(&mut self) -> &mut Self {
+            let subject = self.subject();
+
+            if subject % 2 != 0 {
+                AssertionFailure::from_spec(self)
+                    .with_assertion_name("is_even")
+                    .with_context(format!("remainder: <{}>", subject % 2))
+                    .fail_with_message(format!("expected <{}> to be even", subject));
+            }
+
+            self
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [count]\n\texpected <3> to be even\
+                   \n\n\tremainder: <1>\n")]
+    fn should_panic_with_message_and_context_of_extension_assertion() {
+        assert_that(&3u32).named(&"count").is_even();
+    }
+
+    #[test]
+    fn should_return_assertion_error_of_extension_assertion() {
+        let result = check(|| {
+            assert_that(&3u32).is_even();
+        });
+
+        let error = result.unwrap_err();
+
+        assert_that(&error.assertion_name).is_equal_to(Some("is_even"));
+        assert_that(&error.message).is_equal_to(Some("expected <3> to be even".to_string()));
+        assert_that(&error.context).is_equal_to(vec!["remainder: <1>".to_string()]);
+    }
+
     #[derive(Debug, PartialEq)]
     struct TestStruct {
         pub value: u8,
@@ -0,0 +1,583 @@
+//! Reusable matchers which can be passed to `Spec::satisfies` (or its alias `Spec::is`).
+//!
+//! A `Matcher` decides whether a value matches, and describes both what it expected and why a
+//! value did not match, so that domain specific checks can be written once and still produce
+//! the usual expected/actual failure messages.
+//!
+//! ```rust,ignore
+//! struct HasStatus(u16);
+//!
+//! impl Matcher<Response> for HasStatus {
+//!     fn matches(&self, actual: &Response) -> bool {
+//!         actual.status == self.0
+//!     }
+//!
+//!     fn description(&self) -> String {
+//!         format!("response with status <{}>", self.0)
+//!     }
+//!
+//!     fn describe_mismatch(&self, actual: &Response) -> String {
+//!         format!("response with status <{}>", actual.status)
+//!     }
+//! }
+//!
+//! assert_that(&response).satisfies(HasStatus(404));
+//! ```
+
+use std::fmt::Debug;
+
+/// Creates a matcher which matches if all of the provided matchers match.
+///
+/// ```rust,ignore
+/// assert_that(&"abz").is(all_of![starts_with("a"), ends_with("z")]);
+/// ```
+#[macro_export]
+macro_rules! all_of {
+    ($($matcher:expr),+ $(,)*) => {
+        $crate::matcher::all_of(vec![$($crate::matcher::boxed($matcher)),+])
+    };
+}
+
+/// Creates a matcher which matches if any of the provided matchers match.
+///
+/// ```rust,ignore
+/// assert_that(&"xyz").is(any_of![starts_with("a"), ends_with("z")]);
+/// ```
+#[macro_export]
+macro_rules! any_of {
+    ($($matcher:expr),+ $(,)*) => {
+        $crate::matcher::any_of(vec![$($crate::matcher::boxed($matcher)),+])
+    };
+}
+
+/// A value which can be matched against subjects of type `T`.
+pub trait Matcher<T: ?Sized> {
+    /// Returns whether the provided value matches.
+    fn matches(&self, actual: &T) -> bool;
+
+    /// Describes what a matching value looks like. This is used as the expected value of a
+    /// failure message.
+    fn description(&self) -> String;
+
+    /// Describes why the provided value did not match. This is used as the actual value of a
+    /// failure message.
+    fn describe_mismatch(&self, actual: &T) -> String;
+}
+
+impl<T: ?Sized, M: Matcher<T> + ?Sized> Matcher<T> for &M {
+    fn matches(&self, actual: &T) -> bool {
+        (**self).matches(actual)
+    }
+
+    fn description(&self) -> String {
+        (**self).description()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        (**self).describe_mismatch(actual)
+    }
+}
+
+impl<T: ?Sized, M: Matcher<T> + ?Sized> Matcher<T> for Box<M> {
+    fn matches(&self, actual: &T) -> bool {
+        (**self).matches(actual)
+    }
+
+    fn description(&self) -> String {
+        (**self).description()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        (**self).describe_mismatch(actual)
+    }
+}
+
+/// Matches values equal to the expected value.
+///
+/// This is created by the `equal_to` function.
+#[derive(Debug)]
+pub struct EqualTo<E> {
+    expected: E,
+}
+
+/// Matches values for which the predicate returns `true`.
+///
+/// This is created by the `predicate` function.
+pub struct Predicate<F> {
+    description: String,
+    predicate: F,
+}
+
+/// Creates a matcher for values equal to `expected`.
+///
+/// ```rust,ignore
+/// assert_that(&5).is(equal_to(5));
+/// ```
+pub fn equal_to<E>(expected: E) -> EqualTo<E> {
+    EqualTo { expected }
+}
+
+/// Creates a matcher from a predicate, described by `description` in failure messages.
+///
+/// ```rust,ignore
+/// let is_even = predicate("an even number", |value: &i32| value % 2 == 0);
+/// assert_that(&4).satisfies(is_even);
+/// ```
+pub fn predicate<T: ?Sized, F>(description: &str, predicate: F) -> Predicate<F>
+    where F: Fn(&T) -> bool
+{
+    Predicate {
+        description: description.to_string(),
+        predicate,
+    }
+}
+
+impl<T, E> Matcher<T> for EqualTo<E>
+    where T: Debug + PartialEq<E> + ?Sized,
+          E: Debug
+{
+    fn matches(&self, actual: &T) -> bool {
+        actual.eq(&self.expected)
+    }
+
+    fn description(&self) -> String {
+        format!("<{:?}>", self.expected)
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        format!("<{:?}>", actual)
+    }
+}
+
+impl<T, F> Matcher<T> for Predicate<F>
+    where T: Debug + ?Sized,
+          F: Fn(&T) -> bool
+{
+    fn matches(&self, actual: &T) -> bool {
+        (self.predicate)(actual)
+    }
+
+    fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        format!("<{:?}>", actual)
+    }
+}
+
+/// Matches values which the wrapped matcher does not match.
+///
+/// This is created by the `not` function.
+#[derive(Debug)]
+pub struct Not<M> {
+    matcher: M,
+}
+
+/// Matches values which every one of the wrapped matchers matches.
+///
+/// This is created by the `all_of` function or the `all_of!` macro.
+pub struct AllOf<'a, T: ?Sized> {
+    matchers: Vec<Box<dyn Matcher<T> + 'a>>,
+}
+
+/// Matches values which at least one of the wrapped matchers matches.
+///
+/// This is created by the `any_of` function or the `any_of!` macro.
+pub struct AnyOf<'a, T: ?Sized> {
+    matchers: Vec<Box<dyn Matcher<T> + 'a>>,
+}
+
+/// Replaces the description of the wrapped matcher.
+///
+/// This is created by the `described_as` function.
+#[derive(Debug)]
+pub struct DescribedAs<M> {
+    description: String,
+    matcher: M,
+}
+
+/// Matches strings containing the expected value.
+///
+/// This is created by the `contains` function.
+#[derive(Debug)]
+pub struct Contains<'e> {
+    expected: &'e str,
+}
+
+/// Matches strings starting with the expected value.
+///
+/// This is created by the `starts_with` function.
+#[derive(Debug)]
+pub struct StartsWith<'e> {
+    expected: &'e str,
+}
+
+/// Matches strings ending with the expected value.
+///
+/// This is created by the `ends_with` function.
+#[derive(Debug)]
+pub struct EndsWith<'e> {
+    expected: &'e str,
+}
+
+/// Matches iterables containing the expected item.
+///
+/// This is created by the `has_item` function.
+#[derive(Debug)]
+pub struct HasItem<E> {
+    expected: E,
+}
+
+/// Creates a matcher for values which `matcher` does not match.
+///
+/// ```rust,ignore
+/// assert_that(&"hello").is(not(contains("x")));
+/// ```
+pub fn not<M>(matcher: M) -> Not<M> {
+    Not { matcher }
+}
+
+/// Creates a matcher for values which every one of `matchers` matches. The `all_of!` macro
+/// boxes the matchers for you.
+pub fn all_of<'a, T: ?Sized>(matchers: Vec<Box<dyn Matcher<T> + 'a>>) -> AllOf<'a, T> {
+    AllOf { matchers }
+}
+
+/// Creates a matcher for values which at least one of `matchers` matches. The `any_of!` macro
+/// boxes the matchers for you.
+pub fn any_of<'a, T: ?Sized>(matchers: Vec<Box<dyn Matcher<T> + 'a>>) -> AnyOf<'a, T> {
+    AnyOf { matchers }
+}
+
+/// Creates a matcher which behaves like `matcher`, but is described by `description` in
+/// failure messages.
+///
+/// ```rust,ignore
+/// let valid_id = all_of![starts_with("id-"), not(contains(" "))];
+/// assert_that(&id).is(described_as("a valid id", valid_id));
+/// ```
+pub fn described_as<M>(description: &str, matcher: M) -> DescribedAs<M> {
+    DescribedAs {
+        description: description.to_string(),
+        matcher,
+    }
+}
+
+/// Creates a matcher for strings containing `expected`.
+pub fn contains<'e>(expected: &'e str) -> Contains<'e> {
+    Contains { expected }
+}
+
+/// Creates a matcher for strings starting with `expected`.
+pub fn starts_with<'e>(expected: &'e str) -> StartsWith<'e> {
+    StartsWith { expected }
+}
+
+/// Creates a matcher for strings ending with `expected`.
+pub fn ends_with<'e>(expected: &'e str) -> EndsWith<'e> {
+    EndsWith { expected }
+}
+
+/// Creates a matcher for iterables, such as a `Vec`, containing `expected`.
+///
+/// ```rust,ignore
+/// assert_that(&vec![1, 2, 3]).is(not(has_item(4)));
+/// ```
+pub fn has_item<E>(expected: E) -> HasItem<E> {
+    HasItem { expected }
+}
+
+/// Boxes a matcher so that it can be combined with matchers of other types. This is used by
+/// the `all_of!` and `any_of!` macros.
+#[doc(hidden)]
+pub fn boxed<'a, T: ?Sized, M: Matcher<T> + 'a>(matcher: M) -> Box<dyn Matcher<T> + 'a> {
+    Box::new(matcher)
+}
+
+impl<T: ?Sized, M: Matcher<T>> Matcher<T> for Not<M> {
+    fn matches(&self, actual: &T) -> bool {
+        !self.matcher.matches(actual)
+    }
+
+    fn description(&self) -> String {
+        format!("not {}", self.matcher.description())
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        self.matcher.describe_mismatch(actual)
+    }
+}
+
+impl<'a, T: ?Sized> Matcher<T> for AllOf<'a, T> {
+    fn matches(&self, actual: &T) -> bool {
+        self.matchers.iter().all(|matcher| matcher.matches(actual))
+    }
+
+    fn description(&self) -> String {
+        format!("all of [{}]", describe_all(&self.matchers))
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        let failed: Vec<&Box<dyn Matcher<T> + 'a>> = self.matchers
+            .iter()
+            .filter(|matcher| !matcher.matches(actual))
+            .collect();
+
+        match failed.first() {
+            Some(first) => {
+                format!("{}, which did not satisfy [{}]",
+                        first.describe_mismatch(actual),
+                        describe_all(&failed))
+            }
+            None => "all matched".to_string(),
+        }
+    }
+}
+
+impl<'a, T: ?Sized> Matcher<T> for AnyOf<'a, T> {
+    fn matches(&self, actual: &T) -> bool {
+        self.matchers.iter().any(|matcher| matcher.matches(actual))
+    }
+
+    fn description(&self) -> String {
+        format!("any of [{}]", describe_all(&self.matchers))
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        match self.matchers.first() {
+            Some(first) => {
+                format!("{}, which did not satisfy [{}]",
+                        first.describe_mismatch(actual),
+                        describe_all(&self.matchers))
+            }
+            None => "no matchers to satisfy".to_string(),
+        }
+    }
+}
+
+impl<T: ?Sized, M: Matcher<T>> Matcher<T> for DescribedAs<M> {
+    fn matches(&self, actual: &T) -> bool {
+        self.matcher.matches(actual)
+    }
+
+    fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        self.matcher.describe_mismatch(actual)
+    }
+}
+
+impl<'e, T: AsRef<str> + ?Sized> Matcher<T> for Contains<'e> {
+    fn matches(&self, actual: &T) -> bool {
+        actual.as_ref().contains(self.expected)
+    }
+
+    fn description(&self) -> String {
+        format!("string containing <{:?}>", self.expected)
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        format!("<{:?}>", actual.as_ref())
+    }
+}
+
+impl<'e, T: AsRef<str> + ?Sized> Matcher<T> for StartsWith<'e> {
+    fn matches(&self, actual: &T) -> bool {
+        actual.as_ref().starts_with(self.expected)
+    }
+
+    fn description(&self) -> String {
+        format!("string starting with <{:?}>", self.expected)
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        format!("<{:?}>", actual.as_ref())
+    }
+}
+
+impl<'e, T: AsRef<str> + ?Sized> Matcher<T> for EndsWith<'e> {
+    fn matches(&self, actual: &T) -> bool {
+        actual.as_ref().ends_with(self.expected)
+    }
+
+    fn description(&self) -> String {
+        format!("string ending with <{:?}>", self.expected)
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        format!("<{:?}>", actual.as_ref())
+    }
+}
+
+impl<T, E> Matcher<T> for HasItem<E>
+    where T: Debug + ?Sized,
+          for<'a> &'a T: IntoIterator<Item = &'a E>,
+          E: Debug + PartialEq
+{
+    fn matches(&self, actual: &T) -> bool {
+        actual.into_iter().any(|item| item.eq(&self.expected))
+    }
+
+    fn description(&self) -> String {
+        format!("iterator containing <{:?}>", self.expected)
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        format!("<{:?}>", actual)
+    }
+}
+
+fn describe_all<T: ?Sized, M: Matcher<T>>(matchers: &[M]) -> String {
+    matchers.iter()
+        .map(|matcher| matcher.description())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+    use super::*;
+
+    #[derive(Debug)]
+    struct Response {
+        status: u16,
+    }
+
+    struct HasStatus(u16);
+
+    impl Matcher<Response> for HasStatus {
+        fn matches(&self, actual: &Response) -> bool {
+            actual.status == self.0
+        }
+
+        fn description(&self) -> String {
+            format!("response with status <{}>", self.0)
+        }
+
+        fn describe_mismatch(&self, actual: &Response) -> String {
+            format!("response with status <{}>", actual.status)
+        }
+    }
+
+    #[test]
+    fn should_not_panic_if_value_satisfies_matcher() {
+        assert_that(&Response { status: 404 }).satisfies(HasStatus(404));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: response with status <404>\
+                   \n\t but was: response with status <200>")]
+    fn should_panic_if_value_does_not_satisfy_matcher() {
+        assert_that(&Response { status: 200 }).satisfies(HasStatus(404));
+    }
+
+    #[test]
+    fn should_allow_matchers_to_be_reused() {
+        let not_found = HasStatus(404);
+
+        assert_that(&Response { status: 404 }).is(&not_found);
+        assert_that(&Response { status: 404 }).is(&not_found);
+    }
+
+    #[test]
+    fn should_allow_boxed_matchers() {
+        let matcher: Box<dyn Matcher<Response>> = Box::new(HasStatus(404));
+
+        assert_that(&Response { status: 404 }).is(matcher);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_equal_to_expected() {
+        assert_that(&"hello").is(equal_to("hello"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2>\n\t but was: <1>")]
+    fn should_panic_if_value_is_not_equal_to_expected() {
+        assert_that(&1).is(equal_to(2));
+    }
+
+    #[test]
+    fn should_not_panic_if_value_matches_predicate() {
+        assert_that(&4).satisfies(predicate("an even number", |value: &i32| value % 2 == 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: an even number\n\t but was: <3>")]
+    fn should_panic_if_value_does_not_match_predicate() {
+        assert_that(&3).satisfies(predicate("an even number", |value: &i32| value % 2 == 0));
+    }
+
+    #[test]
+    fn should_not_panic_if_negated_matcher_does_not_match() {
+        assert_that(&"hello").is(not(contains("x")));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: not string containing <\"ell\">\
+                   \n\t but was: <\"hello\">")]
+    fn should_panic_if_negated_matcher_matches() {
+        assert_that(&"hello").is(not(contains("ell")));
+    }
+
+    #[test]
+    fn should_not_panic_if_all_matchers_match() {
+        assert_that(&"abz").is(all_of![starts_with("a"), ends_with("z")]);
+        assert_that(&"abz".to_string()).is(all_of![starts_with("a"), not(contains("x")),]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all of [string starting with <\"a\">, \
+                   string containing <\"b\">, \
+                   string ending with <\"z\">]\n\t but was: <\"axy\">, which did not satisfy \
+                   [string containing <\"b\">, string ending with <\"z\">]")]
+    fn should_panic_listing_failed_matchers_if_any_matcher_does_not_match() {
+        assert_that(&"axy").is(all_of![starts_with("a"), contains("b"), ends_with("z")]);
+    }
+
+    #[test]
+    fn should_not_panic_if_any_matcher_matches() {
+        assert_that(&"xyz").is(any_of![starts_with("a"), ends_with("z")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: any of [string starting with <\"a\">, \
+                   string ending with \
+                   <\"z\">]\n\t but was: <\"xy\">, which did not satisfy [string starting with \
+                   <\"a\">, string ending with <\"z\">]")]
+    fn should_panic_if_no_matcher_matches() {
+        assert_that(&"xy").is(any_of![starts_with("a"), ends_with("z")]);
+    }
+
+    #[test]
+    fn should_allow_matchers_of_different_types_to_be_combined() {
+        let is_even = predicate("an even number", |value: &i32| value % 2 == 0);
+        assert_that(&4).is(all_of![equal_to(4), is_even]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a valid id\
+                   \n\t but was: <\"id 1\">, which did not satisfy \
+                   [not string containing <\" \">]")]
+    fn should_panic_with_description_of_described_matcher() {
+        let valid_id = all_of![starts_with("id"), not(contains(" "))];
+        assert_that(&"id 1").is(described_as("a valid id", valid_id));
+    }
+
+    #[test]
+    fn should_not_panic_if_iterable_has_item() {
+        assert_that(&vec![1, 2, 3]).is(has_item(2));
+        assert_that(&vec![1, 2, 3]).is(not(has_item(4)));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator containing <4>\n\t but was: <[1, 2, 3]>")]
+    fn should_panic_if_iterable_does_not_have_item() {
+        assert_that(&vec![1, 2, 3]).is(has_item(4));
+    }
+}
@@ -10,10 +10,10 @@ use num::Float;
 pub trait OrderedAssertions<T>
     where T: Debug + PartialOrd
 {
-    fn is_less_than<E: Borrow<T>>(&mut self, other: E);
-    fn is_less_than_or_equal_to<E: Borrow<T>>(&mut self, other: E);
-    fn is_greater_than<E: Borrow<T>>(&mut self, other: E);
-    fn is_greater_than_or_equal_to<E: Borrow<T>>(&mut self, other: E);
+    fn is_less_than<E: Borrow<T>>(&mut self, other: E) -> &mut Self;
+    fn is_less_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) -> &mut Self;
+    fn is_greater_than<E: Borrow<T>>(&mut self, other: E) -> &mut Self;
+    fn is_greater_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) -> &mut Self;
 }
 
 impl<'s, T> OrderedAssertions<T> for Spec<'s, T>
@@ -25,16 +25,19 @@ impl<'s, T> OrderedAssertions<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&1).is_less_than(&2);
     /// ```
-    fn is_less_than<E: Borrow<T>>(&mut self, other: E) {
-        let subject = self.subject;
+    fn is_less_than<E: Borrow<T>>(&mut self, other: E) -> &mut Self {
+        let subject = self.subject();
         let borrowed_other = other.borrow();
 
         if subject >= borrowed_other {
             AssertionFailure::from_spec(self)
+                .with_assertion_name("is_less_than")
                 .with_expected(format!("value less than <{:?}>", borrowed_other))
                 .with_actual(format!("<{:?}>", subject))
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject is less than or equal to the expected value. The subject type
@@ -43,16 +46,19 @@ impl<'s, T> OrderedAssertions<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&2).is_less_than_or_equal_to(&2);
     /// ```
-    fn is_less_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) {
-        let subject = self.subject;
+    fn is_less_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) -> &mut Self {
+        let subject = self.subject();
         let borrowed_other = other.borrow();
 
         if subject > borrowed_other {
             AssertionFailure::from_spec(self)
+                .with_assertion_name("is_less_than_or_equal_to")
                 .with_expected(format!("value less than or equal to <{:?}>", borrowed_other))
                 .with_actual(format!("<{:?}>", subject))
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject is greater than the expected value. The subject type must
@@ -61,16 +67,19 @@ impl<'s, T> OrderedAssertions<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&2).is_greater_than(&1);
     /// ```
-    fn is_greater_than<E: Borrow<T>>(&mut self, other: E) {
-        let subject = self.subject;
+    fn is_greater_than<E: Borrow<T>>(&mut self, other: E) -> &mut Self {
+        let subject = self.subject();
         let borrowed_other = other.borrow();
 
         if subject <= borrowed_other {
             AssertionFailure::from_spec(self)
+                .with_assertion_name("is_greater_than")
                 .with_expected(format!("value greater than <{:?}>", borrowed_other))
                 .with_actual(format!("<{:?}>", subject))
                 .fail();
         }
+
+        self
     }
 
     /// Asserts that the subject is greater than or equal to the expected value. The subject type
@@ -79,22 +88,25 @@ impl<'s, T> OrderedAssertions<T> for Spec<'s, T>
     /// ```rust,ignore
     /// assert_that(&2).is_greater_than_or_equal_to(&1);
     /// ```
-    fn is_greater_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) {
-        let subject = self.subject;
+    fn is_greater_than_or_equal_to<E: Borrow<T>>(&mut self, other: E) -> &mut Self {
+        let subject = self.subject();
         let borrowed_other = other.borrow();
 
         if subject < borrowed_other {
             AssertionFailure::from_spec(self)
+                .with_assertion_name("is_greater_than_or_equal_to")
                 .with_expected(format!("value greater than or equal to <{:?}>", borrowed_other))
                 .with_actual(format!("<{:?}>", subject))
                 .fail();
         }
+
+        self
     }
 }
 
 #[cfg(feature = "num")]
 pub trait FloatAssertions<T: Float> {
-    fn is_close_to<E: Borrow<T>, O: Borrow<T>>(&mut self, expected: E, tolerance: O);
+    fn is_close_to<E: Borrow<T>, O: Borrow<T>>(&mut self, expected: E, tolerance: O) -> &mut Self;
 }
 
 #[cfg(feature = "num")]
@@ -105,8 +117,8 @@ impl<'s, T: Float + Debug> FloatAssertions<T> for Spec<'s, T> {
     /// ```rust,ignore
     /// assert_that(&2.0f64).is_close_to(2.0f64, 0.01f64);
     /// ```
-    fn is_close_to<E: Borrow<T>, O: Borrow<T>>(&mut self, expected: E, tolerance: O) {
-        let subject = *self.subject;
+    fn is_close_to<E: Borrow<T>, O: Borrow<T>>(&mut self, expected: E, tolerance: O) -> &mut Self {
+        let subject = *self.subject();
         let borrowed_expected = expected.borrow();
         let borrowed_tolerance = tolerance.borrow();
 
@@ -114,12 +126,15 @@ impl<'s, T: Float + Debug> FloatAssertions<T> for Spec<'s, T> {
 
         if !subject.is_finite() || difference > borrowed_tolerance.abs() {
             AssertionFailure::from_spec(self)
+                .with_assertion_name("is_close_to")
                 .with_expected(format!("float close to <{:?}> (tolerance of <{:?}>)",
                                        borrowed_expected,
                                        borrowed_tolerance))
                 .with_actual(format!("<{:?}>", subject))
                 .fail();
         }
+
+        self
     }
 }
 
@@ -137,6 +152,11 @@ mod tests {
         assert_that(&1).is_less_than(&2);
     }
 
+    #[test]
+    fn should_allow_chaining_ordered_assertions() {
+        assert_that(&5).is_greater_than(&1).is_less_than(&10).is_not_equal_to(&7);
+    }
+
     #[test]
     fn should_not_panic_if_value_is_less_than_expected() {
         assert_that(&1).is_less_than(&2);
@@ -7,14 +7,14 @@ use std::fmt::Debug;
 pub trait OptionAssertions<'r, T>
     where T: Debug
 {
-    fn is_some(&mut self) -> Spec<'r, T>;
-    fn is_none(&mut self);
+    fn is_some(&mut self) -> Spec<'_, T>;
+    fn is_none(&mut self) -> &mut Self;
 }
 
 pub trait ContainingOptionAssertions<T>
     where T: Debug + PartialEq
 {
-    fn contains_value<E: Borrow<T>>(&mut self, expected_value: E);
+    fn contains_value<E: Borrow<T>>(&mut self, expected_value: E) -> &mut Self;
 }
 
 impl<'s, T> ContainingOptionAssertions<T> for Spec<'s, Option<T>>
@@ -26,13 +26,14 @@ impl<'s, T> ContainingOptionAssertions<T> for Spec<'s, Option<T>>
     /// ```rust,ignore
     /// assert_that(&Some(1)).contains_value(&1);
     /// ```
-    fn contains_value<E: Borrow<T>>(&mut self, expected_value: E) {
+    fn contains_value<E: Borrow<T>>(&mut self, expected_value: E) -> &mut Self {
         let borrowed_expected_value = expected_value.borrow();
 
-        match *self.subject {
+        match *self.subject() {
             Some(ref val) => {
                 if !val.eq(borrowed_expected_value) {
                     AssertionFailure::from_spec(self)
+                        .with_assertion_name("contains_value")
                         .with_expected(format!("option to contain <{:?}>",
                                                borrowed_expected_value))
                         .with_actual(format!("<{:?}>", val))
@@ -41,11 +42,14 @@ impl<'s, T> ContainingOptionAssertions<T> for Spec<'s, Option<T>>
             }
             None => {
                 AssertionFailure::from_spec(self)
+                    .with_assertion_name("contains_value")
                     .with_expected(format!("option<{:?}>", borrowed_expected_value))
                     .with_actual(format!("option[none]"))
                     .fail();
             }
         };
+
+        self
     }
 }
 
@@ -59,23 +63,15 @@ impl<'s, T> OptionAssertions<'s, T> for Spec<'s, Option<T>>
     /// ```rust,ignore
     /// assert_that(&Some(1)).is_some();
     /// ```
-    fn is_some(&mut self) -> Spec<'s, T> {
-        match *self.subject {
-            Some(ref val) => {
-                Spec {
-                    subject: val,
-                    subject_name: self.subject_name,
-                    location: self.location.clone(),
-                    description: self.description,
-                }
-            }
+    fn is_some(&mut self) -> Spec<'_, T> {
+        match *self.subject() {
+            Some(ref val) => self.derive(val),
             None => {
                 AssertionFailure::from_spec(self)
+                    .with_assertion_name("is_some")
                     .with_expected(format!("option[some]"))
                     .with_actual(format!("option[none]"))
-                    .fail();
-
-                unreachable!();
+                    .fail_fatal();
             }
         }
     }
@@ -85,16 +81,19 @@ impl<'s, T> OptionAssertions<'s, T> for Spec<'s, Option<T>>
     /// ```rust,ignore
     /// assert_that(&Option::None::<String>).is_none();
     /// ```
-    fn is_none(&mut self) {
-        match *self.subject {
+    fn is_none(&mut self) -> &mut Self {
+        match *self.subject() {
             None => (),
             Some(ref val) => {
                 AssertionFailure::from_spec(self)
+                    .with_assertion_name("is_none")
                     .with_expected(format!("option[none]"))
                     .with_actual(format!("option<{:?}>", val))
                     .fail();
             }
         }
+
+        self
     }
 }
 
@@ -4,11 +4,11 @@ use std::borrow::Borrow;
 use std::path::{Path, PathBuf};
 
 pub trait PathAssertions {
-    fn exists(&mut self);
-    fn does_not_exist(&mut self);
-    fn is_a_file(&mut self);
-    fn is_a_directory(&mut self);
-    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E);
+    fn exists(&mut self) -> &mut Self;
+    fn does_not_exist(&mut self) -> &mut Self;
+    fn is_a_file(&mut self) -> &mut Self;
+    fn is_a_directory(&mut self) -> &mut Self;
+    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) -> &mut Self;
 }
 
 
@@ -18,8 +18,10 @@ impl<'s> PathAssertions for Spec<'s, &'s Path> {
     /// ```rust,ignore
     /// assert_that(&Path::new("/tmp/file")).exists();
     /// ```
-    fn exists(&mut self) {
-        exists(self.subject, self)
+    fn exists(&mut self) -> &mut Self {
+        exists(self.subject(), self);
+
+        self
     }
 
     /// Asserts that the subject `Path` does not refer to an existing location.
@@ -27,8 +29,10 @@ impl<'s> PathAssertions for Spec<'s, &'s Path> {
     /// ```rust,ignore
     /// assert_that(&Path::new("/tmp/file")).does_not_exist();
     /// ```
-    fn does_not_exist(&mut self) {
-        does_not_exist(self.subject, self)
+    fn does_not_exist(&mut self) -> &mut Self {
+        does_not_exist(self.subject(), self);
+
+        self
     }
 
     /// Asserts that the subject `Path` refers to an existing file.
@@ -36,8 +40,10 @@ impl<'s> PathAssertions for Spec<'s, &'s Path> {
     /// ```rust,ignore
     /// assert_that(&Path::new("/tmp/file")).is_a_file();
     /// ```
-    fn is_a_file(&mut self) {
-        is_a_file(self.subject, self)
+    fn is_a_file(&mut self) -> &mut Self {
+        is_a_file(self.subject(), self);
+
+        self
     }
 
     /// Asserts that the subject `Path` refers to an existing directory.
@@ -45,8 +51,10 @@ impl<'s> PathAssertions for Spec<'s, &'s Path> {
     /// ```rust,ignore
     /// assert_that(&Path::new("/tmp/dir/")).is_a_directory();
     /// ```
-    fn is_a_directory(&mut self) {
-        is_a_directory(self.subject, self)
+    fn is_a_directory(&mut self) -> &mut Self {
+        is_a_directory(self.subject(), self);
+
+        self
     }
 
     /// Asserts that the subject `Path` has the expected file name.
@@ -54,8 +62,10 @@ impl<'s> PathAssertions for Spec<'s, &'s Path> {
     /// ```rust,ignore
     /// assert_that(&Path::new("/tmp/file")).has_file_name(&"file");
     /// ```
-    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) {
-        has_file_name(self.subject, expected_file_name.borrow(), self)
+    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) -> &mut Self {
+        has_file_name(self.subject(), expected_file_name.borrow(), self);
+
+        self
     }
 }
 
@@ -65,8 +75,10 @@ impl<'s> PathAssertions for Spec<'s, PathBuf> {
     /// ```rust,ignore
     /// assert_that(&PathBuf::from("/tmp/file")).exists();
     /// ```
-    fn exists(&mut self) {
-        exists(self.subject.as_path(), self)
+    fn exists(&mut self) -> &mut Self {
+        exists(self.subject().as_path(), self);
+
+        self
     }
 
     /// Asserts that the subject `PathBuf` does not refer to an existing location.
@@ -74,8 +86,10 @@ impl<'s> PathAssertions for Spec<'s, PathBuf> {
     /// ```rust,ignore
     /// assert_that(&PathBuf::from("/tmp/file")).does_not_exist();
     /// ```
-    fn does_not_exist(&mut self) {
-        does_not_exist(self.subject.as_path(), self)
+    fn does_not_exist(&mut self) -> &mut Self {
+        does_not_exist(self.subject().as_path(), self);
+
+        self
     }
 
     /// Asserts that the subject `PathBuf` refers to an existing file.
@@ -83,8 +97,10 @@ impl<'s> PathAssertions for Spec<'s, PathBuf> {
     /// ```rust,ignore
     /// assert_that(&PathBuf::from("/tmp/file")).is_a_file();
     /// ```
-    fn is_a_file(&mut self) {
-        is_a_file(self.subject.as_path(), self)
+    fn is_a_file(&mut self) -> &mut Self {
+        is_a_file(self.subject().as_path(), self);
+
+        self
     }
 
     /// Asserts that the subject `PathBuf` refers to an existing directory.
@@ -92,8 +108,10 @@ impl<'s> PathAssertions for Spec<'s, PathBuf> {
     /// ```rust,ignore
     /// assert_that(&PathBuf::from("/tmp/dir/")).is_a_directory();
     /// ```
-    fn is_a_directory(&mut self) {
-        is_a_directory(self.subject.as_path(), self)
+    fn is_a_directory(&mut self) -> &mut Self {
+        is_a_directory(self.subject().as_path(), self);
+
+        self
     }
 
     /// Asserts that the subject `PathBuf` has the expected file name.
@@ -101,14 +119,17 @@ impl<'s> PathAssertions for Spec<'s, PathBuf> {
     /// ```rust,ignore
     /// assert_that(&PathBuf::from("/tmp/file")).has_file_name(&"file");
     /// ```
-    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) {
-        has_file_name(self.subject.as_path(), expected_file_name.borrow(), self)
+    fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) -> &mut Self {
+        has_file_name(self.subject().as_path(), expected_file_name.borrow(), self);
+
+        self
     }
 }
 
 fn exists<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
     if !subject.exists() {
         AssertionFailure::from_spec(spec)
+            .with_assertion_name("exists")
             .with_expected(format!("Path of <{:?}> to exist", subject))
             .with_actual(format!("a non-existent Path"))
             .fail();
@@ -118,6 +139,7 @@ fn exists<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
 fn does_not_exist<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
     if subject.exists() {
         AssertionFailure::from_spec(spec)
+            .with_assertion_name("does_not_exist")
             .with_expected(format!("Path of <{:?}> to not exist", subject))
             .with_actual(format!("a resolvable Path"))
             .fail();
@@ -127,6 +149,7 @@ fn does_not_exist<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
 fn is_a_file<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
     if !subject.is_file() {
         AssertionFailure::from_spec(spec)
+            .with_assertion_name("is_a_file")
             .with_expected(format!("Path of <{:?}> to be a file", subject))
             .with_actual(format!("not a resolvable file"))
             .fail();
@@ -136,6 +159,7 @@ fn is_a_file<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
 fn is_a_directory<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
     if !subject.is_dir() {
         AssertionFailure::from_spec(spec)
+            .with_assertion_name("is_a_directory")
             .with_expected(format!("Path of <{:?}> to be a directory", subject))
             .with_actual(format!("not a resolvable directory"))
             .fail();
@@ -154,7 +178,7 @@ fn has_file_name<'s, S: DescriptiveSpec<'s>>(subject: &Path,
                     fail_from_file_name(spec,
                                         expected_file_name,
                                         format!("an invalid UTF-8 file name"));
-                    unreachable!();
+                    return;
                 }
             }
         }
@@ -162,7 +186,7 @@ fn has_file_name<'s, S: DescriptiveSpec<'s>>(subject: &Path,
             fail_from_file_name(spec,
                                 expected_file_name,
                                 format!("a non-resolvable path <{:?}>", subject));
-            unreachable!();
+            return;
         }
     };
 
@@ -173,6 +197,7 @@ fn has_file_name<'s, S: DescriptiveSpec<'s>>(subject: &Path,
 
 fn fail_from_file_name<'s, S: DescriptiveSpec<'s>>(spec: &'s S, expected: &str, actual: String) {
     AssertionFailure::from_spec(spec)
+        .with_assertion_name("has_file_name")
         .with_expected(build_file_name_message(expected))
         .with_actual(actual)
         .fail();
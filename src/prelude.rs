@@ -1,14 +1,20 @@
-pub use super::{asserting, assert_that};
+pub use super::{asserting, assert_that, check, SoftAssertions};
 pub use super::boolean::BooleanAssertions;
-pub use super::hashmap::HashMapAssertions;
+pub use super::hashmap::{HashMapAssertions, MapAssertions};
+pub use super::matcher::Matcher;
 pub use super::iter::{ContainingIntoIterAssertions, ContainingIteratorAssertions,
                       MappingIterAssertions};
 pub use super::numeric::OrderedAssertions;
 pub use super::option::{OptionAssertions, ContainingOptionAssertions};
 pub use super::path::PathAssertions;
 pub use super::result::{ContainingResultAssertions, ResultAssertions};
-pub use super::string::StrAssertions;
-pub use super::vec::VecAssertions;
+pub use super::set::SetAssertions;
+pub use super::string::{CharAssertions, OsStrAssertions, StrAssertions};
+pub use super::vec::{SliceAssertions, VecAssertions};
 
+#[cfg(feature = "regex")]
+pub use super::string::RegexAssertions;
 #[cfg(feature = "num")]
 pub use super::numeric::FloatAssertions;
+#[cfg(feature = "snapshot")]
+pub use super::snapshot::SnapshotAssertions;
@@ -7,16 +7,18 @@ pub trait ResultAssertions<'s, T, E>
     where T: Debug,
           E: Debug
 {
-    fn is_ok(&mut self) -> Spec<'s, T>;
-    fn is_err(&mut self) -> Spec<'s, E>;
+    fn is_ok(&mut self) -> Spec<'_, T>;
+    fn is_err(&mut self) -> Spec<'_, E>;
 }
 
 pub trait ContainingResultAssertions<T, E>
     where T: Debug,
           E: Debug
 {
-    fn is_ok_containing<V: Borrow<T>>(&mut self, expected_value: V) where T: PartialEq;
-    fn is_err_containing<V: Borrow<E>>(&mut self, expected_value: V) where E: PartialEq;
+    fn is_ok_containing<V: Borrow<T>>(&mut self, expected_value: V) -> &mut Self
+        where T: PartialEq;
+    fn is_err_containing<V: Borrow<E>>(&mut self, expected_value: V) -> &mut Self
+        where E: PartialEq;
 }
 
 impl<'s, T, E> ContainingResultAssertions<T, E> for Spec<'s, Result<T, E>>
@@ -29,15 +31,16 @@ impl<'s, T, E> ContainingResultAssertions<T, E> for Spec<'s, Result<T, E>>
     /// ```rust,ignore
     /// assert_that(&Result::Ok::<usize, usize>(1)).is_ok_containing(&1);
     /// ```
-    fn is_ok_containing<V: Borrow<T>>(&mut self, expected_value: V)
+    fn is_ok_containing<V: Borrow<T>>(&mut self, expected_value: V) -> &mut Self
         where T: PartialEq
     {
         let borrowed_expected_value = expected_value.borrow();
 
-        match *self.subject {
+        match *self.subject() {
             Ok(ref val) => {
                 if !val.eq(borrowed_expected_value) {
                     AssertionFailure::from_spec(self)
+                        .with_assertion_name("is_ok_containing")
                         .with_expected(build_detail_message("ok", borrowed_expected_value))
                         .with_actual(build_detail_message("ok", val))
                         .fail();
@@ -45,11 +48,14 @@ impl<'s, T, E> ContainingResultAssertions<T, E> for Spec<'s, Result<T, E>>
             }
             Err(ref val) => {
                 AssertionFailure::from_spec(self)
+                    .with_assertion_name("is_ok_containing")
                     .with_expected(build_detail_message("ok", borrowed_expected_value))
                     .with_actual(build_detail_message("err", val))
                     .fail();
             }
         }
+
+        self
     }
 
     /// Asserts that the subject is an `Err` Result containing the expected value.
@@ -58,15 +64,16 @@ impl<'s, T, E> ContainingResultAssertions<T, E> for Spec<'s, Result<T, E>>
     /// ```rust,ignore
     /// assert_that(&Result::Err::<usize, usize>(1)).is_err_containing(&1);
     /// ```
-    fn is_err_containing<V: Borrow<E>>(&mut self, expected_value: V)
+    fn is_err_containing<V: Borrow<E>>(&mut self, expected_value: V) -> &mut Self
         where E: PartialEq
     {
         let borrowed_expected_value = expected_value.borrow();
 
-        match *self.subject {
+        match *self.subject() {
             Err(ref val) => {
                 if !val.eq(borrowed_expected_value) {
                     AssertionFailure::from_spec(self)
+                        .with_assertion_name("is_err_containing")
                         .with_expected(build_detail_message("err", borrowed_expected_value))
                         .with_actual(build_detail_message("err", val))
                         .fail();
@@ -74,11 +81,14 @@ impl<'s, T, E> ContainingResultAssertions<T, E> for Spec<'s, Result<T, E>>
             }
             Ok(ref val) => {
                 AssertionFailure::from_spec(self)
+                    .with_assertion_name("is_err_containing")
                     .with_expected(build_detail_message("err", borrowed_expected_value))
                     .with_actual(build_detail_message("ok", val))
                     .fail();
             }
         }
+
+        self
     }
 }
 
@@ -97,23 +107,15 @@ impl<'s, T, E> ResultAssertions<'s, T, E> for Spec<'s, Result<T, E>>
     /// ```rust,ignore
     /// assert_that(&Result::Ok::<usize, usize>(1)).is_ok();
     /// ```
-    fn is_ok(&mut self) -> Spec<'s, T> {
-        match *self.subject {
-            Ok(ref val) => {
-                Spec {
-                    subject: val,
-                    subject_name: self.subject_name,
-                    location: self.location.clone(),
-                    description: self.description,
-                }
-            }
+    fn is_ok(&mut self) -> Spec<'_, T> {
+        match *self.subject() {
+            Ok(ref val) => self.derive(val),
             Err(ref err) => {
                 AssertionFailure::from_spec(self)
+                    .with_assertion_name("is_ok")
                     .with_expected(format!("result[ok]"))
                     .with_actual(format!("result[error]<{:?}>", err))
-                    .fail();
-
-                unreachable!();
+                    .fail_fatal();
             }
         }
     }
@@ -125,23 +127,15 @@ impl<'s, T, E> ResultAssertions<'s, T, E> for Spec<'s, Result<T, E>>
     /// ```rust,ignore
     /// assert_that(&Result::Err::<usize, usize>(1)).is_err();
     /// ```
-    fn is_err(&mut self) -> Spec<'s, E> {
-        match *self.subject {
-            Err(ref val) => {
-                Spec {
-                    subject: val,
-                    subject_name: self.subject_name,
-                    location: self.location.clone(),
-                    description: self.description,
-                }
-            }
+    fn is_err(&mut self) -> Spec<'_, E> {
+        match *self.subject() {
+            Err(ref val) => self.derive(val),
             Ok(ref val) => {
                 AssertionFailure::from_spec(self)
+                    .with_assertion_name("is_err")
                     .with_expected(format!("result[error]"))
                     .with_actual(format!("result[ok]<{:?}>", val))
-                    .fail();
-
-                unreachable!();
+                    .fail_fatal();
             }
         }
     }
@@ -0,0 +1,301 @@
+use super::{AssertionFailure, Spec};
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
+
+/// A collection of unique items, such as a `HashSet` with any hasher or a `BTreeSet`.
+///
+/// This is what `SetAssertions` are implemented against, and can be implemented for other set
+/// types to make the assertions available for them.
+pub trait Set {
+    type Item;
+
+    /// The name of the set type, used in failure messages.
+    const NAME: &'static str;
+
+    fn set_len(&self) -> usize;
+    fn set_contains(&self, item: &Self::Item) -> bool;
+    fn set_items(&self) -> Vec<&Self::Item>;
+
+    /// Returns the items of the set which are not among the provided values, looking the values
+    /// up in the same way as the set looks up its own items.
+    fn set_items_not_in(&self, values: &[&Self::Item]) -> Vec<&Self::Item>;
+}
+
+pub trait SetAssertions<'s, T: 's> {
+    fn has_length(&mut self, expected: usize) -> &mut Self;
+    fn is_empty(&mut self) -> &mut Self;
+    fn is_subset_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where &'s E: IntoIterator<Item = &'s T>;
+    fn is_superset_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where &'s E: IntoIterator<Item = &'s T>;
+    fn is_disjoint_from<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where &'s E: IntoIterator<Item = &'s T>;
+}
+
+impl<T, S> Set for HashSet<T, S>
+    where T: Hash + Eq,
+          S: BuildHasher
+{
+    type Item = T;
+    const NAME: &'static str = "hashset";
+
+    fn set_len(&self) -> usize {
+        self.len()
+    }
+
+    fn set_contains(&self, item: &T) -> bool {
+        self.contains(item)
+    }
+
+    fn set_items(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    fn set_items_not_in(&self, values: &[&T]) -> Vec<&T> {
+        let values: HashSet<&T> = values.iter().cloned().collect();
+        self.iter().filter(|item| !values.contains(item)).collect()
+    }
+}
+
+impl<T> Set for BTreeSet<T>
+    where T: Ord
+{
+    type Item = T;
+    const NAME: &'static str = "btreeset";
+
+    fn set_len(&self) -> usize {
+        self.len()
+    }
+
+    fn set_contains(&self, item: &T) -> bool {
+        self.contains(item)
+    }
+
+    fn set_items(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    fn set_items_not_in(&self, values: &[&T]) -> Vec<&T> {
+        let values: BTreeSet<&T> = values.iter().cloned().collect();
+        self.iter().filter(|item| !values.contains(item)).collect()
+    }
+}
+
+impl<'s, S> SetAssertions<'s, S::Item> for Spec<'s, S>
+    where S: Set,
+          S::Item: PartialEq + Debug
+{
+    /// Asserts that the length of the subject set is equal to the provided length. The subject
+    /// type must be a `Set`, such as a `HashSet` or `BTreeSet`.
+    ///
+    /// ```rust,ignore
+    /// let test_set: HashSet<u8> = [1, 2].iter().cloned().collect();
+    /// assert_that(&test_set).has_length(2);
+    /// ```
+    fn has_length(&mut self, expected: usize) -> &mut Self {
+        let length = self.subject().set_len();
+
+        if length != expected {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("has_length")
+                .with_expected(format!("{} to have length <{}>", S::NAME, expected))
+                .with_actual(format!("<{}>", length))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject set is empty. The subject type must be a `Set`, such as a
+    /// `HashSet` or `BTreeSet`.
+    ///
+    /// ```rust,ignore
+    /// let test_set: HashSet<u8> = HashSet::new();
+    /// assert_that(&test_set).is_empty();
+    /// ```
+    fn is_empty(&mut self) -> &mut Self {
+        let length = self.subject().set_len();
+
+        if length != 0 {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("is_empty")
+                .with_expected(format!("an empty {}", S::NAME))
+                .with_actual(format!("a {} with length <{:?}>", S::NAME, length))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that every item of the subject set is one of the provided values. The subject
+    /// type must be a `Set`, such as a `HashSet` or `BTreeSet`.
+    ///
+    /// ```rust,ignore
+    /// let test_set: BTreeSet<u8> = [1, 2].iter().cloned().collect();
+    /// assert_that(&test_set).is_subset_of(&vec![1, 2, 3]);
+    /// ```
+    fn is_subset_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where &'s E: IntoIterator<Item = &'s S::Item>
+    {
+        let subject = self.subject();
+        let expected_values: Vec<&S::Item> = expected_values_iter.into_iter().collect();
+
+        let unexpected_values = subject.set_items_not_in(&expected_values);
+
+        if !unexpected_values.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("is_subset_of")
+                .with_expected(format!("{} to be a subset of <{:?}>", S::NAME, expected_values))
+                .with_actual(format!("<{:?}>", subject.set_items()))
+                .with_context(format!("unexpected items: <{:?}>", unexpected_values))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject set contains every one of the provided values. The subject type
+    /// must be a `Set`, such as a `HashSet` or `BTreeSet`.
+    ///
+    /// ```rust,ignore
+    /// let test_set: BTreeSet<u8> = [1, 2, 3].iter().cloned().collect();
+    /// assert_that(&test_set).is_superset_of(&vec![1, 2]);
+    /// ```
+    fn is_superset_of<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where &'s E: IntoIterator<Item = &'s S::Item>
+    {
+        let subject = self.subject();
+        let expected_values: Vec<&S::Item> = expected_values_iter.into_iter().collect();
+
+        let missing_values: Vec<&&S::Item> = expected_values.iter()
+            .filter(|value| !subject.set_contains(value))
+            .collect();
+
+        if !missing_values.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("is_superset_of")
+                .with_expected(format!("{} to be a superset of <{:?}>", S::NAME, expected_values))
+                .with_actual(format!("<{:?}>", subject.set_items()))
+                .with_context(format!("missing items: <{:?}>", missing_values))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject set contains none of the provided values. The subject type must
+    /// be a `Set`, such as a `HashSet` or `BTreeSet`.
+    ///
+    /// ```rust,ignore
+    /// let test_set: BTreeSet<u8> = [1, 2].iter().cloned().collect();
+    /// assert_that(&test_set).is_disjoint_from(&vec![3, 4]);
+    /// ```
+    fn is_disjoint_from<E: 's>(&mut self, expected_values_iter: &'s E) -> &mut Self
+        where &'s E: IntoIterator<Item = &'s S::Item>
+    {
+        let subject = self.subject();
+        let expected_values: Vec<&S::Item> = expected_values_iter.into_iter().collect();
+
+        let shared_values: Vec<&&S::Item> = expected_values.iter()
+            .filter(|value| subject.set_contains(value))
+            .collect();
+
+        if !shared_values.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("is_disjoint_from")
+                .with_expected(format!("{} to be disjoint from <{:?}>", S::NAME, expected_values))
+                .with_actual(format!("<{:?}>", subject.set_items()))
+                .with_context(format!("shared items: <{:?}>", shared_values))
+                .fail();
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    use std::collections::{BTreeSet, HashSet};
+
+    fn btree_set(values: &[u8]) -> BTreeSet<u8> {
+        values.iter().cloned().collect()
+    }
+
+    #[test]
+    fn should_not_panic_if_hashset_length_matches_expected() {
+        let test_set: HashSet<u8> = [1, 2].iter().cloned().collect();
+        assert_that(&test_set).has_length(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: btreeset to have length <1>\n\t but was: <2>")]
+    fn should_panic_if_btreeset_length_does_not_match_expected() {
+        assert_that(&btree_set(&[1, 2])).has_length(1);
+    }
+
+    #[test]
+    fn should_not_panic_if_set_was_expected_to_be_empty_and_is() {
+        let test_set: HashSet<u8> = HashSet::new();
+        assert_that(&test_set).is_empty();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: an empty hashset\
+                   \n\t but was: a hashset with length <1>")]
+    fn should_panic_if_set_was_expected_to_be_empty_and_is_not() {
+        let test_set: HashSet<u8> = [1].iter().cloned().collect();
+        assert_that(&test_set).is_empty();
+    }
+
+    #[test]
+    fn should_not_panic_if_set_is_subset() {
+        assert_that(&btree_set(&[1, 2])).is_subset_of(&vec![1, 2, 3]);
+        assert_that(&btree_set(&[1, 2])).is_subset_of(&btree_set(&[1, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: btreeset to be a subset of <[1, 2]>\
+                   \n\t but was: <[1, 3, 4]>\
+                   \n\n\tunexpected items: <[3, 4]>")]
+    fn should_panic_if_set_is_not_subset() {
+        assert_that(&btree_set(&[1, 3, 4])).is_subset_of(&vec![1, 2]);
+    }
+
+    #[test]
+    fn should_not_panic_if_set_is_superset() {
+        assert_that(&btree_set(&[1, 2, 3])).is_superset_of(&vec![1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: btreeset to be a superset of <[1, 4]>\
+                   \n\t but was: <[1, 2, 3]>\
+                   \n\n\tmissing items: <[4]>")]
+    fn should_panic_if_set_is_not_superset() {
+        assert_that(&btree_set(&[1, 2, 3])).is_superset_of(&vec![1, 4]);
+    }
+
+    #[test]
+    fn should_not_panic_if_set_is_disjoint() {
+        let test_set: HashSet<u8> = [1, 2].iter().cloned().collect();
+        assert_that(&test_set).is_disjoint_from(&vec![3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: btreeset to be disjoint from <[2, 3]>\
+                   \n\t but was: <[1, 2]>\
+                   \n\n\tshared items: <[2]>")]
+    fn should_panic_if_set_is_not_disjoint() {
+        assert_that(&btree_set(&[1, 2])).is_disjoint_from(&vec![2, 3]);
+    }
+
+    #[test]
+    fn should_allow_set_assertions_together_with_iterator_assertions() {
+        let test_set = btree_set(&[1, 2, 3]);
+        assert_that(&test_set).has_length(3).contains(&2).is_superset_of(&vec![1]);
+    }
+}
@@ -0,0 +1,1076 @@
+//! Snapshot assertions, which compare the rendering of a subject against one stored by an
+//! earlier run.
+//!
+//! Snapshots are stored in a `snapshots` directory next to the source file of the assertion, in a
+//! file named after the source file, the running test and the line of the assertion, so adding
+//! assertions elsewhere in the test does not move existing snapshots. An assertion reached more
+//! than once by a test, such as one in a loop or helper function, is also numbered by how many
+//! times its line has been reached. Outside of a test, the test name is left out.
+//!
+//! A missing snapshot fails the assertion, unless the `SPECTRAL_ACCEPT_NEW_SNAPSHOTS` environment
+//! variable is set to store it instead. Set `SPECTRAL_UPDATE_SNAPSHOTS` to both store missing
+//! snapshots and overwrite those which do not match.
+//!
+//! Inline snapshots are instead kept in the test itself, as the string literal passed to
+//! `matches_inline_snapshot`. Setting the same environment variable rewrites that literal in the
+//! source file.
+//!
+//! The location of the assertion is the one recorded by the `assert_that!` and `asserting!`
+//! macros, so snapshot assertions must be made through them.
+//!
+//! ```rust,ignore
+//! assert_that!(render(&page)).matches_display_snapshot();
+//! assert_that!(parse("1 + 2")).matches_inline_snapshot(r#"Sum(1, 2)"#);
+//! ```
+
+use super::{diff, AssertionFailure, Spec};
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::{Debug, Display};
+use std::fs;
+use std::io;
+use std::panic::Location;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+
+const UPDATE_VARIABLE: &str = "SPECTRAL_UPDATE_SNAPSHOTS";
+const ACCEPT_NEW_VARIABLE: &str = "SPECTRAL_ACCEPT_NEW_SNAPSHOTS";
+const INLINE_ASSERTION: &str = "matches_inline_snapshot";
+
+/// The inline snapshots rewritten so far by this test run, as the source file, the line the
+/// assertion was originally on and the number of lines the rewrite added. Later rewrites in the
+/// same file use these to find their assertion, as the recorded lines no longer match the file.
+static INLINE_REWRITES: Mutex<Vec<(PathBuf, u32, isize)>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// The number of times each line with a file snapshot assertion has been reached by the test
+    /// running on this thread, which the test harness runs each test on.
+    static SNAPSHOT_HITS: RefCell<HashMap<(String, u32), u32>> = RefCell::new(HashMap::new());
+}
+
+/// How snapshot assertions treat stored snapshots, chosen by environment variables.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SnapshotMode {
+    /// Compares against stored snapshots, failing if there is none.
+    Compare,
+    /// Compares against stored snapshots, storing the rendering if there is none.
+    AcceptNew,
+    /// Stores the rendering whether or not it matches, and rewrites inline snapshots.
+    Update,
+}
+
+/// Identifies a snapshot among those stored for a source file.
+#[derive(Debug, PartialEq)]
+struct SnapshotKey {
+    /// The name of the running test, if the assertion is made in one.
+    test_name: Option<String>,
+    line: u32,
+    /// How many times the line has been reached, counting this assertion.
+    hit: u32,
+}
+
+pub trait SnapshotAssertions<S> {
+    fn matches_snapshot(&mut self) -> &mut Self where S: Debug;
+    fn matches_display_snapshot(&mut self) -> &mut Self where S: Display;
+    fn matches_inline_snapshot(&mut self, expected: &str) -> &mut Self where S: Debug;
+}
+
+impl<'s, S> SnapshotAssertions<S> for Spec<'s, S> {
+    /// Asserts that the pretty-printed `Debug` representation of the subject matches its stored
+    /// snapshot, storing it if there is none yet.
+    ///
+    /// ```rust,ignore
+    /// assert_that!(parse("1 + 2")).matches_snapshot();
+    /// ```
+    fn matches_snapshot(&mut self) -> &mut Self
+        where S: Debug
+    {
+        let rendering = format!("{:#?}", self.subject());
+        check_snapshot(self, "matches_snapshot", &rendering, SnapshotMode::from_environment());
+
+        self
+    }
+
+    /// Asserts that the `Display` representation of the subject matches its stored snapshot,
+    /// storing it if there is none yet. This stores strings as they are, rather than quoted and
+    /// escaped as `matches_snapshot` would.
+    ///
+    /// ```rust,ignore
+    /// assert_that!(render(&page)).matches_display_snapshot();
+    /// ```
+    fn matches_display_snapshot(&mut self) -> &mut Self
+        where S: Display
+    {
+        let rendering = self.subject().to_string();
+        check_snapshot(self,
+                       "matches_display_snapshot",
+                       &rendering,
+                       SnapshotMode::from_environment());
+
+        self
+    }
+
+    /// Asserts that the pretty-printed `Debug` representation of the subject matches the
+    /// provided inline snapshot. A snapshot spanning several lines may be indented to match the
+    /// surrounding code, as its common indentation and the line breaks after the opening and
+    /// before the closing quote are ignored.
+    ///
+    /// When snapshots are being updated, the literal is rewritten in the source file instead of
+    /// failing. It is found as the first argument of this call, which must be in the statement
+    /// starting at the location recorded by the `assert_that!` macro.
+    ///
+    /// ```rust,ignore
+    /// assert_that!(parse("1 + 2")).matches_inline_snapshot(r#"
+    ///     Sum(
+    ///         1,
+    ///         2,
+    ///     )
+    /// "#);
+    /// ```
+    #[track_caller]
+    fn matches_inline_snapshot(&mut self, expected: &str) -> &mut Self
+        where S: Debug
+    {
+        let rendering = format!("{:#?}", self.subject());
+        check_inline_snapshot(self,
+                              expected,
+                              &rendering,
+                              SnapshotMode::from_environment(),
+                              Location::caller());
+
+        self
+    }
+}
+
+impl SnapshotMode {
+    fn from_environment() -> SnapshotMode {
+        if is_enabled(UPDATE_VARIABLE) {
+            SnapshotMode::Update
+        } else if is_enabled(ACCEPT_NEW_VARIABLE) {
+            SnapshotMode::AcceptNew
+        } else {
+            SnapshotMode::Compare
+        }
+    }
+}
+
+enum SnapshotError {
+    Missing,
+    Mismatch(String),
+    Io(io::Error),
+}
+
+/// The file and line recorded by the `assert_that!` macro, failing the assertion if there are
+/// none.
+fn assertion_location<'a, S>(spec: &'a Spec<S>,
+                             assertion_name: &'static str)
+                             -> Option<(&'a str, u32)> {
+    let location = spec.location.as_ref().and_then(|location| split_location(location));
+
+    if location.is_none() {
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name(assertion_name)
+            .fail_with_message("snapshot assertions require the location recorded by the \
+                                assert_that! macro"
+                .to_string());
+    }
+
+    location
+}
+
+fn check_snapshot<S>(spec: &Spec<S>,
+                     assertion_name: &'static str,
+                     rendering: &str,
+                     mode: SnapshotMode) {
+    let (file, line) = match assertion_location(spec, assertion_name) {
+        Some(location) => location,
+        None => return,
+    };
+
+    let path = snapshot_path(&resolve_source_path(file), &next_snapshot_key(file, line));
+
+    match compare_snapshot(&path, rendering, mode) {
+        Ok(()) => {}
+        Err(SnapshotError::Missing) => {
+            AssertionFailure::from_spec(spec)
+                .with_assertion_name(assertion_name)
+                .with_context(format!("set {}=1 to store new snapshots", ACCEPT_NEW_VARIABLE))
+                .fail_with_message(format!("no snapshot stored at <{}>", path.display()));
+        }
+        Err(SnapshotError::Mismatch(stored)) => {
+            let mut failure = AssertionFailure::from_spec(spec);
+            failure.with_assertion_name(assertion_name);
+
+            if let Some(difference) = diff::describe_text_difference(&stored, rendering) {
+                failure.with_context(difference);
+            }
+
+            failure.with_context(format!("set {}=1 to update the snapshot", UPDATE_VARIABLE))
+                .fail_with_message(format!("value did not match snapshot <{}>", path.display()));
+        }
+        Err(SnapshotError::Io(error)) => {
+            AssertionFailure::from_spec(spec)
+                .with_assertion_name(assertion_name)
+                .fail_with_message(format!("could not access snapshot <{}>: {}",
+                                           path.display(),
+                                           error));
+        }
+    }
+}
+
+fn check_inline_snapshot<S>(spec: &Spec<S>,
+                            expected: &str,
+                            rendering: &str,
+                            mode: SnapshotMode,
+                            caller: &Location) {
+    let expected = normalise_inline_snapshot(expected);
+
+    if expected == rendering {
+        return;
+    }
+
+    if mode != SnapshotMode::Update {
+        let mut failure = AssertionFailure::from_spec(spec);
+        failure.with_assertion_name("matches_inline_snapshot");
+
+        if let Some(difference) = diff::describe_text_difference(&expected, rendering) {
+            failure.with_context(difference);
+        }
+
+        failure.with_context(format!("set {}=1 to update the snapshot", UPDATE_VARIABLE))
+            .fail_with_message("value did not match inline snapshot".to_string());
+
+        return;
+    }
+
+    let (file, line) = match assertion_location(spec, "matches_inline_snapshot") {
+        Some(location) => location,
+        None => return,
+    };
+
+    if caller.file() != file || caller.line() < line {
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name("matches_inline_snapshot")
+            .fail_with_message(format!("could not update inline snapshot at <{}:{}>, as it is not \
+                                        in the statement of the assert_that! macro at <{}:{}>",
+                                       caller.file(),
+                                       caller.line(),
+                                       file,
+                                       line));
+
+        return;
+    }
+
+    let path = resolve_source_path(file);
+
+    if let Err(error) = update_inline_snapshot(&path,
+                                               line,
+                                               caller.line(),
+                                               caller.column(),
+                                               rendering) {
+        AssertionFailure::from_spec(spec)
+            .with_assertion_name("matches_inline_snapshot")
+            .fail_with_message(format!("could not update inline snapshot at <{}:{}>: {}",
+                                       file,
+                                       line,
+                                       error));
+    }
+}
+
+/// Compares the rendering against the snapshot stored at the path, storing it instead if the
+/// mode allows it.
+fn compare_snapshot(path: &Path, rendering: &str, mode: SnapshotMode) -> Result<(), SnapshotError> {
+    if mode != SnapshotMode::Update {
+        match fs::read_to_string(path) {
+            Ok(stored) => {
+                let stored = stored.strip_suffix('\n').unwrap_or(&stored);
+
+                return if stored == rendering {
+                    Ok(())
+                } else {
+                    Err(SnapshotError::Mismatch(stored.to_string()))
+                };
+            }
+            Err(ref error) if error.kind() == io::ErrorKind::NotFound => {
+                if mode == SnapshotMode::Compare {
+                    return Err(SnapshotError::Missing);
+                }
+            }
+            Err(error) => return Err(SnapshotError::Io(error)),
+        }
+    }
+
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(SnapshotError::Io)?;
+    }
+
+    fs::write(path, format!("{}\n", rendering)).map_err(SnapshotError::Io)
+}
+
+fn snapshot_path(source_path: &Path, key: &SnapshotKey) -> PathBuf {
+    let source_name = source_path.file_stem().and_then(|stem| stem.to_str()).unwrap_or("unknown");
+    let mut file_name = sanitise(source_name);
+
+    if let Some(ref test_name) = key.test_name {
+        file_name.push_str(&format!("__{}", sanitise(&test_name.replace("::", "__"))));
+    }
+
+    file_name.push_str(&format!("__line_{}", key.line));
+
+    if key.hit > 1 {
+        file_name.push_str(&format!("_{}", key.hit));
+    }
+
+    file_name.push_str(".snap");
+
+    source_path.parent().unwrap_or_else(|| Path::new("")).join("snapshots").join(file_name)
+}
+
+/// Removes the line breaks after the opening and before the closing quote of an inline snapshot
+/// spanning several lines, along with the indentation common to its lines.
+fn normalise_inline_snapshot(snapshot: &str) -> String {
+    if !snapshot.contains('\n') {
+        return snapshot.to_string();
+    }
+
+    let mut lines: Vec<&str> = snapshot.split('\n').collect();
+
+    if lines.first().is_some_and(|line| line.trim().is_empty()) {
+        lines.remove(0);
+    }
+
+    if lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let indentation = lines.iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines.iter()
+        .map(|line| if line.len() > indentation { &line[indentation..] } else { line.trim() })
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+/// Renders a raw string literal holding the rendering, indented one level deeper than the line
+/// the literal starts on if it spans several lines.
+fn inline_snapshot_literal(rendering: &str, indentation: &str) -> String {
+    let mut longest_hashes = 0;
+
+    for (index, _) in rendering.match_indices('"') {
+        let hashes = rendering[index + 1..].chars().take_while(|&c| c == '#').count();
+        longest_hashes = longest_hashes.max(hashes);
+    }
+
+    let hashes = "#".repeat(longest_hashes + 1);
+
+    if !rendering.contains('\n') {
+        return format!("r{}\"{}\"{}", hashes, rendering, hashes);
+    }
+
+    let lines: Vec<String> = rendering.split('\n')
+        .map(|line| if line.is_empty() {
+            String::new()
+        } else {
+            format!("{}    {}", indentation, line)
+        })
+        .collect();
+
+    format!("r{}\"\n{}\n{}\"{}", hashes, lines.join("\n"), indentation, hashes)
+}
+
+/// A token of Rust source, as far as is needed to find the literal passed to an inline snapshot
+/// assertion.
+#[derive(Debug, PartialEq)]
+enum TokenKind<'a> {
+    Identifier(&'a str),
+    StringLiteral,
+    Punctuation(char),
+    /// A character or numeric literal, or a lifetime.
+    Other,
+}
+
+#[derive(Debug)]
+struct Token<'a> {
+    kind: TokenKind<'a>,
+    start: usize,
+    end: usize,
+    line: u32,
+    column: u32,
+}
+
+/// Splits the statement starting at the line of the source into tokens, up to the first `;`
+/// outside of any brackets or the bracket closing the block the statement is in.
+fn statement_tokens(source: &str, line: u32) -> Result<Vec<Token<'_>>, String> {
+    let mut position = source.split_inclusive('\n')
+        .take(line.saturating_sub(1) as usize)
+        .map(str::len)
+        .sum::<usize>();
+    let mut current_line = line;
+    let mut line_start = position;
+    let mut depth = 0usize;
+    let mut tokens = Vec::new();
+
+    while let Some(c) = source[position..].chars().next() {
+        let rest = &source[position..];
+        let start = position;
+        let token_line = current_line;
+        let column = source[line_start..start].chars().count() as u32 + 1;
+
+        let (kind, length) = if c.is_whitespace() {
+            position += c.len_utf8();
+
+            if c == '\n' {
+                current_line += 1;
+                line_start = position;
+            }
+
+            continue;
+        } else if rest.starts_with("//") {
+            position += rest.find('\n').unwrap_or(rest.len());
+            continue;
+        } else if rest.starts_with("/*") {
+            let length = block_comment_length(rest)?;
+            position += length;
+            current_line += rest[..length].matches('\n').count() as u32;
+            line_start = source[..position].rfind('\n').map_or(0, |index| index + 1);
+            continue;
+        } else if c.is_alphabetic() || c == '_' {
+            let identifier_length = rest.find(|c: char| !c.is_alphanumeric() && c != '_')
+                .unwrap_or(rest.len());
+            let identifier = &rest[..identifier_length];
+            let after = &rest[identifier_length..];
+
+            match identifier {
+                "r" | "br" if after.starts_with('"') || after.starts_with("#\"") ||
+                              after.starts_with("##") => {
+                    (TokenKind::StringLiteral, identifier_length + raw_string_length(after)?)
+                }
+                "b" if after.starts_with('"') => {
+                    (TokenKind::StringLiteral, identifier_length + string_length(after)?)
+                }
+                "b" if after.starts_with('\'') => {
+                    (TokenKind::Other, identifier_length + quote_length(after))
+                }
+                _ => (TokenKind::Identifier(identifier), identifier_length),
+            }
+        } else if c == '"' {
+            (TokenKind::StringLiteral, string_length(rest)?)
+        } else if c == '\'' {
+            (TokenKind::Other, quote_length(rest))
+        } else if c.is_ascii_digit() {
+            let length = rest.char_indices()
+                .find(|&(index, c)| {
+                    !(c.is_alphanumeric() || c == '_' ||
+                      c == '.' && rest[index + 1..].starts_with(|c: char| c.is_ascii_digit()))
+                })
+                .map_or(rest.len(), |(index, _)| index);
+
+            (TokenKind::Other, length)
+        } else {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' if depth == 0 => break,
+                ')' | ']' | '}' => depth -= 1,
+                ';' if depth == 0 => break,
+                _ => {}
+            }
+
+            (TokenKind::Punctuation(c), c.len_utf8())
+        };
+
+        position += length;
+        current_line += source[start..position].matches('\n').count() as u32;
+
+        if current_line != token_line {
+            line_start = source[..position].rfind('\n').map_or(0, |index| index + 1);
+        }
+
+        tokens.push(Token {
+            kind,
+            start,
+            end: position,
+            line: token_line,
+            column,
+        });
+    }
+
+    Ok(tokens)
+}
+
+fn block_comment_length(comment: &str) -> Result<usize, String> {
+    let mut depth = 0;
+    let mut index = 0;
+
+    while index < comment.len() {
+        if comment[index..].starts_with("/*") {
+            depth += 1;
+            index += 2;
+        } else if comment[index..].starts_with("*/") {
+            depth -= 1;
+            index += 2;
+
+            if depth == 0 {
+                return Ok(index);
+            }
+        } else {
+            index += comment[index..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+
+    Err("a block comment is not terminated".to_string())
+}
+
+/// The length of the string literal at the start of the source, including its quotes.
+fn string_length(literal: &str) -> Result<usize, String> {
+    let mut escaped = false;
+
+    literal.char_indices()
+        .skip(1)
+        .find(|&(_, c)| {
+            let closing = c == '"' && !escaped;
+            escaped = c == '\\' && !escaped;
+            closing
+        })
+        .map(|(index, _)| index + 1)
+        .ok_or_else(|| "the snapshot literal is not terminated".to_string())
+}
+
+/// The length of the raw string literal after its `r` prefix at the start of the source.
+fn raw_string_length(literal: &str) -> Result<usize, String> {
+    let hashes = literal.chars().take_while(|&c| c == '#').count();
+    let closing = format!("\"{}", "#".repeat(hashes));
+
+    if !literal[hashes..].starts_with('"') {
+        return Err("the snapshot is not a string literal".to_string());
+    }
+
+    literal[hashes + 1..]
+        .find(&closing)
+        .map(|index| hashes + 1 + index + closing.len())
+        .ok_or_else(|| "the snapshot literal is not terminated".to_string())
+}
+
+/// The length of the character literal or lifetime at the start of the source.
+fn quote_length(source: &str) -> usize {
+    let mut chars = source.char_indices().skip(1);
+
+    match chars.next() {
+        Some((_, '\\')) => {
+            source.get(3..)
+                .and_then(|rest| rest.find('\''))
+                .map_or(source.len(), |index| index + 4)
+        }
+        Some((_, c)) => {
+            let after = 1 + c.len_utf8();
+
+            if source[after..].starts_with('\'') {
+                after + 1
+            } else {
+                source[1..]
+                    .find(|c: char| !c.is_alphanumeric() && c != '_')
+                    .map_or(source.len(), |index| index + 1)
+            }
+        }
+        None => source.len(),
+    }
+}
+
+/// Finds the byte range of the string literal passed to the `matches_inline_snapshot` call at
+/// the line and column of the call, within the statement starting at the line of the macro.
+fn find_inline_snapshot(source: &str,
+                        line: u32,
+                        call_line: u32,
+                        call_column: u32)
+                        -> Result<(usize, usize), String> {
+    let tokens = statement_tokens(source, line)?;
+
+    let call = tokens.iter()
+        .position(|token| {
+            token.kind == TokenKind::Identifier(INLINE_ASSERTION) && token.line == call_line &&
+            token.column == call_column
+        })
+        .ok_or_else(|| "no matches_inline_snapshot call found".to_string())?;
+
+    match (tokens.get(call + 1), tokens.get(call + 2)) {
+        (Some(open), Some(literal)) if open.kind == TokenKind::Punctuation('(') &&
+                                       literal.kind == TokenKind::StringLiteral => {
+            Ok((literal.start, literal.end))
+        }
+        _ => Err("the snapshot is not a string literal".to_string()),
+    }
+}
+
+/// Rewrites the inline snapshot of the assertion recorded at the line of the source file, whose
+/// `matches_inline_snapshot` call is at the call line and column.
+fn update_inline_snapshot(path: &Path,
+                          line: u32,
+                          call_line: u32,
+                          call_column: u32,
+                          rendering: &str)
+                          -> Result<(), String> {
+    let mut rewrites = INLINE_REWRITES.lock().unwrap_or_else(|error| error.into_inner());
+
+    let added_lines: isize = rewrites.iter()
+        .filter(|&&(ref rewritten, rewritten_line, _)| rewritten == path && rewritten_line < line)
+        .map(|&(_, _, added)| added)
+        .sum();
+    let shift = |line: u32| (line as isize + added_lines).max(1) as u32;
+
+    let source = fs::read_to_string(path).map_err(|error| error.to_string())?;
+    let (start, end) = find_inline_snapshot(&source, shift(line), shift(call_line), call_column)?;
+
+    let line_start = source[..start].rfind('\n').map(|index| index + 1).unwrap_or(0);
+    let indentation: String = source[line_start..]
+        .chars()
+        .take_while(|c| c.is_whitespace() && *c != '\n')
+        .collect();
+
+    let literal = inline_snapshot_literal(rendering, &indentation);
+    let added = literal.matches('\n').count() as isize -
+                source[start..end].matches('\n').count() as isize;
+
+    let updated = format!("{}{}{}", &source[..start], literal, &source[end..]);
+    fs::write(path, updated).map_err(|error| error.to_string())?;
+
+    rewrites.push((path.to_path_buf(), line, added));
+
+    Ok(())
+}
+
+/// Splits a location recorded by the `assert_that!` macro into its file and line.
+pub(crate) fn split_location(location: &str) -> Option<(&str, u32)> {
+    let index = location.rfind(':')?;
+    let line = location[index + 1..].parse().ok()?;
+
+    Some((&location[..index], line))
+}
+
+/// Finds the source file recorded by `file!()`, which is relative to the workspace root rather
+/// than the package being tested, by looking in the current directory and its ancestors.
+pub(crate) fn resolve_source_path(file: &str) -> PathBuf {
+    if let Ok(current_directory) = env::current_dir() {
+        for directory in current_directory.ancestors() {
+            let candidate = directory.join(file);
+
+            if candidate.exists() {
+                return candidate;
+            }
+        }
+    }
+
+    PathBuf::from(file)
+}
+
+fn is_enabled(variable: &str) -> bool {
+    env::var_os(variable).is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Identifies the snapshot of an assertion at the file and line, within the running test whose
+/// name the test harness gives to the thread running it, if there is one.
+fn next_snapshot_key(file: &str, line: u32) -> SnapshotKey {
+    let hit = SNAPSHOT_HITS.with(|hits| {
+        let mut hits = hits.borrow_mut();
+        let hit = hits.entry((file.to_string(), line)).or_insert(0);
+        *hit += 1;
+        *hit
+    });
+    let test_name = match thread::current().name() {
+        Some(name) if name != "main" => Some(name.to_string()),
+        _ => None,
+    };
+
+    SnapshotKey { test_name, line, hit }
+}
+
+fn sanitise(value: &str) -> String {
+    value.chars()
+        .map(|c| if c.is_alphanumeric() || c == '_' || c == '-' { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+    use super::{check_inline_snapshot, check_snapshot, compare_snapshot, find_inline_snapshot,
+                inline_snapshot_literal, next_snapshot_key, normalise_inline_snapshot,
+                snapshot_path, split_location, update_inline_snapshot, SnapshotError,
+                SnapshotKey, SnapshotMode};
+
+    use std::env;
+    use std::fs;
+    use std::panic::{self, Location};
+    use std::path::{Path, PathBuf};
+    use std::process;
+    use std::thread;
+
+    /// A directory for the files of a test, which is removed along with them when dropped.
+    struct TempDirectory(PathBuf);
+
+    impl TempDirectory {
+        fn new(name: &str) -> TempDirectory {
+            let directory = env::temp_dir().join(format!("spectral-{}-{}", name, process::id()));
+            let _ = fs::remove_dir_all(&directory);
+
+            TempDirectory(directory)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+
+        fn source_location(&self) -> String {
+            format!("{}:12", self.0.join("render.rs").display())
+        }
+    }
+
+    impl Drop for TempDirectory {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Runs the function on a new thread with the name of a test, as a separate run of that test
+    /// would be.
+    fn run_as_test<F, R>(test_name: &str, test: F) -> R
+        where F: FnOnce() -> R + Send + 'static,
+              R: Send + 'static
+    {
+        let thread = thread::Builder::new().name(test_name.to_string()).spawn(test).unwrap();
+
+        match thread.join() {
+            Ok(result) => result,
+            Err(panic) => panic::resume_unwind(panic),
+        }
+    }
+
+    fn check_display_snapshot(location: &str, value: &str, mode: SnapshotMode) {
+        let spec = assert_that(&value).at_location(location.to_string());
+        check_snapshot(&spec, "matches_display_snapshot", value, mode);
+    }
+
+    #[test]
+    fn should_split_location() {
+        assert_that(&split_location("src/lib.rs:12")).is_equal_to(Some(("src/lib.rs", 12)));
+        assert_that(&split_location("src/lib.rs")).is_none();
+    }
+
+    fn key(test_name: Option<&str>, line: u32, hit: u32) -> SnapshotKey {
+        SnapshotKey {
+            test_name: test_name.map(|name| name.to_string()),
+            line,
+            hit,
+        }
+    }
+
+    #[test]
+    fn should_build_snapshot_path_from_file_test_name_and_line() {
+        let key = key(Some("render::tests::should_render"), 12, 1);
+        let path = snapshot_path(Path::new("src/render.rs"), &key);
+        let expected = "src/snapshots/render__render__tests__should_render__line_12.snap";
+
+        assert_that(&path).is_equal_to(PathBuf::from(expected));
+    }
+
+    #[test]
+    fn should_number_snapshot_path_if_line_is_reached_again() {
+        let key = key(Some("render::tests::should_render"), 12, 2);
+        let path = snapshot_path(Path::new("src/render.rs"), &key);
+        let expected = "src/snapshots/render__render__tests__should_render__line_12_2.snap";
+
+        assert_that(&path).is_equal_to(PathBuf::from(expected));
+    }
+
+    #[test]
+    fn should_build_snapshot_path_from_file_and_line_outside_of_test() {
+        let path = snapshot_path(Path::new("src/render.rs"), &key(None, 12, 1));
+
+        assert_that(&path).is_equal_to(PathBuf::from("src/snapshots/render__line_12.snap"));
+    }
+
+    #[test]
+    fn should_count_snapshot_assertions_of_each_test_by_line() {
+        let keys = run_as_test("render::tests::should_render", || {
+            (next_snapshot_key("src/render.rs", 12),
+             next_snapshot_key("src/render.rs", 14),
+             next_snapshot_key("src/render.rs", 12),
+             next_snapshot_key("src/other.rs", 12))
+        });
+        let test_name = Some("render::tests::should_render");
+
+        assert_that(&keys.0).is_equal_to(key(test_name, 12, 1));
+        assert_that(&keys.1).is_equal_to(key(test_name, 14, 1));
+        assert_that(&keys.2).is_equal_to(key(test_name, 12, 2));
+        assert_that(&keys.3).is_equal_to(key(test_name, 12, 1));
+    }
+
+    #[test]
+    fn should_key_snapshot_on_line_outside_of_test() {
+        let snapshot_key = thread::spawn(|| next_snapshot_key("src/render.rs", 12)).join().unwrap();
+
+        assert_that(&snapshot_key).is_equal_to(key(None, 12, 1));
+    }
+
+    #[test]
+    fn should_keep_snapshot_if_earlier_assertion_is_added() {
+        let directory = TempDirectory::new("earlier");
+        let first_location = directory.source_location();
+        let second_location = first_location.replace(":12", ":20");
+
+        let stored_location = second_location.clone();
+
+        run_as_test("render::tests::should_render", move || {
+            check_display_snapshot(&stored_location, "second", SnapshotMode::AcceptNew);
+        });
+        run_as_test("render::tests::should_render", move || {
+            check_display_snapshot(&first_location, "first", SnapshotMode::AcceptNew);
+            check_display_snapshot(&second_location, "second", SnapshotMode::Compare);
+        });
+
+        let snapshots: Vec<_> = fs::read_dir(directory.path().join("snapshots")).unwrap().collect();
+        assert_that(&snapshots).has_length(2);
+    }
+
+    #[test]
+    fn should_only_store_missing_snapshot_if_new_snapshots_are_accepted() {
+        let directory = TempDirectory::new("compare");
+        let path = directory.path().join("value.snap");
+
+        match compare_snapshot(&path, "first", SnapshotMode::Compare) {
+            Err(SnapshotError::Missing) => {}
+            _ => panic!("expected a missing snapshot"),
+        }
+        assert_that(&path).does_not_exist();
+
+        assert_that(&compare_snapshot(&path, "first", SnapshotMode::AcceptNew).is_ok()).is_true();
+        assert_that(&fs::read_to_string(&path).unwrap()).is_equal_to("first\n".to_string());
+        assert_that(&compare_snapshot(&path, "first", SnapshotMode::Compare).is_ok()).is_true();
+
+        match compare_snapshot(&path, "second", SnapshotMode::AcceptNew) {
+            Err(SnapshotError::Mismatch(stored)) => {
+                assert_that(&stored).is_equal_to("first".to_string());
+            }
+            _ => panic!("expected a mismatch"),
+        }
+    }
+
+    #[test]
+    fn should_overwrite_snapshot_if_update_requested() {
+        let directory = TempDirectory::new("update");
+        let path = directory.path().join("value.snap");
+
+        assert_that(&compare_snapshot(&path, "first", SnapshotMode::Update).is_ok()).is_true();
+        assert_that(&compare_snapshot(&path, "second", SnapshotMode::Update).is_ok()).is_true();
+        assert_that(&fs::read_to_string(&path).unwrap()).is_equal_to("second\n".to_string());
+    }
+
+    #[test]
+    fn should_match_snapshots_stored_by_earlier_run() {
+        let directory = TempDirectory::new("matches");
+        let location = directory.source_location();
+
+        for &mode in &[SnapshotMode::AcceptNew, SnapshotMode::Compare] {
+            let location = location.clone();
+
+            run_as_test("render::tests::should_render", move || {
+                check_display_snapshot(&location, "first", mode);
+                check_display_snapshot(&location, "second", mode);
+            });
+        }
+
+        let snapshots: Vec<_> = fs::read_dir(directory.path().join("snapshots")).unwrap().collect();
+        assert_that(&snapshots).has_length(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tno snapshot stored at <")]
+    fn should_panic_if_snapshot_is_missing() {
+        let directory = TempDirectory::new("missing");
+        check_display_snapshot(&directory.source_location(), "first", SnapshotMode::Compare);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tvalue did not match snapshot <")]
+    fn should_panic_if_value_does_not_match_snapshot() {
+        let directory = TempDirectory::new("mismatch");
+        let location = directory.source_location();
+
+        let stored_location = location.clone();
+
+        run_as_test("render::tests::should_render", move || {
+            check_display_snapshot(&stored_location, "first\nsecond", SnapshotMode::AcceptNew);
+        });
+        run_as_test("render::tests::should_render", move || {
+            check_display_snapshot(&location, "first\nthird", SnapshotMode::Compare);
+        });
+    }
+
+    #[test]
+    fn should_show_difference_if_value_does_not_match_snapshot() {
+        let directory = TempDirectory::new("difference");
+        let location = directory.source_location();
+
+        let stored_location = location.clone();
+
+        run_as_test("render::tests::should_render", move || {
+            check_display_snapshot(&stored_location, "first\nsecond", SnapshotMode::AcceptNew);
+        });
+        let result = run_as_test("render::tests::should_render", move || {
+            check(|| check_display_snapshot(&location, "first\nthird", SnapshotMode::Compare))
+        });
+
+        let error = result.unwrap_err();
+        assert_that(&error.context).has_length(2);
+        assert_that(&error.context[0]).ends_with("\n  first\n- second\n+ third");
+        assert_that(&error.context[1])
+            .is_equal_to("set SPECTRAL_UPDATE_SNAPSHOTS=1 to update the snapshot".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tsnapshot assertions require the location recorded by the \
+                               assert_that! macro")]
+    fn should_panic_if_location_is_missing() {
+        assert_that(&1).matches_snapshot();
+    }
+
+    #[test]
+    fn should_match_inline_snapshot() {
+        assert_that!(&vec![1, 2]).matches_inline_snapshot(r#"
+            [
+                1,
+                2,
+            ]
+        "#);
+        assert_that!(&Some(1)).matches_inline_snapshot(r#"
+            Some(
+                1,
+            )"#);
+        assert_that!(&1).matches_inline_snapshot("1");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tvalue did not match inline snapshot\
+                   \n\n\tdiff (- expected, + actual):\
+                   \n\t@@ -1,1 +1,1 @@\
+                   \n\t- 2\
+                   \n\t+ 1")]
+    fn should_panic_if_value_does_not_match_inline_snapshot() {
+        check_inline_snapshot(&assert_that(&1),
+                              "2",
+                              "1",
+                              SnapshotMode::Compare,
+                              Location::caller());
+    }
+
+    #[test]
+    fn should_normalise_inline_snapshot() {
+        assert_that(&normalise_inline_snapshot("\n    a\n\n      b\n    ")).is_equal_to("a\n\n  b"
+            .to_string());
+        assert_that(&normalise_inline_snapshot("  a  ")).is_equal_to("  a  ".to_string());
+    }
+
+    #[test]
+    fn should_render_inline_snapshot_literal_which_normalises_to_rendering() {
+        let rendering = "Some(\n    \"#\",\n)";
+        let literal = inline_snapshot_literal(rendering, "        ");
+
+        assert_that(&literal).is_equal_to("r##\"\n            Some(\n                \"#\",\n   \
+                                           \x20        )\n        \"##"
+            .to_string());
+        assert_that(&normalise_inline_snapshot(&literal[4..literal.len() - 3]))
+            .is_equal_to(rendering.to_string());
+        assert_that(&inline_snapshot_literal("1", "    ")).is_equal_to("r#\"1\"#".to_string());
+    }
+
+    fn find_literal(source: &str,
+                    line: u32,
+                    call_line: u32,
+                    call_column: u32)
+                    -> Result<&str, String> {
+        find_inline_snapshot(source, line, call_line, call_column)
+            .map(|(start, end)| &source[start..end])
+    }
+
+    #[test]
+    fn should_find_inline_snapshot_at_call() {
+        let source = "assert_that!(&1).matches_inline_snapshot(\"1\");\n\
+                      assert_that!(&2)\n    .matches_inline_snapshot( r#\"\"2\"\"# );\n";
+
+        assert_that(&find_literal(source, 1, 1, 18)).is_equal_to(Ok("\"1\""));
+        assert_that(&find_literal(source, 2, 3, 6)).is_equal_to(Ok("r#\"\"2\"\"#"));
+        assert_that(&find_literal(source, 4, 4, 6)).is_err();
+    }
+
+    #[test]
+    fn should_find_inline_snapshot_with_escaped_quotes() {
+        let source = "assert_that!(&s).matches_inline_snapshot(\"a\\\"b\\\\\");";
+
+        assert_that(&find_literal(source, 1, 1, 18)).is_equal_to(Ok("\"a\\\"b\\\\\""));
+    }
+
+    #[test]
+    fn should_find_inline_snapshot_after_whitespace_and_comments() {
+        let source = "assert_that!(&1)\n    .matches_inline_snapshot /* ( */ (\n        \"1\");";
+
+        assert_that(&find_literal(source, 1, 2, 6)).is_equal_to(Ok("\"1\""));
+    }
+
+    #[test]
+    fn should_skip_literals_before_inline_snapshot() {
+        let source = "assert_that!(&'\"').named(r\"x;\").matches_inline_snapshot(\"'\\\"'\");";
+
+        assert_that(&find_literal(source, 1, 1, 33)).is_equal_to(Ok("\"'\\\"'\""));
+    }
+
+    #[test]
+    fn should_only_find_inline_snapshot_in_statement_of_macro() {
+        let source = "assert_that!(&1).is_equal_to(1);\n\
+                      assert_that!(&2).matches_inline_snapshot(\"2\");\n";
+
+        assert_that(&find_literal(source, 1, 2, 18))
+            .is_equal_to(Err("no matches_inline_snapshot call found".to_string()));
+    }
+
+    #[test]
+    fn should_fail_to_find_inline_snapshot_which_is_not_literal() {
+        let source = "assert_that!(&1).matches_inline_snapshot(expected);";
+
+        assert_that(&find_literal(source, 1, 1, 18))
+            .is_equal_to(Err("the snapshot is not a string literal".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tcould not update inline snapshot at <src/snapshot.rs:")]
+    fn should_panic_if_inline_snapshot_is_not_in_statement_of_macro() {
+        let spec = assert_that(&1).at_location("src/render.rs:12".to_string());
+        check_inline_snapshot(&spec, "2", "1", SnapshotMode::Update, Location::caller());
+    }
+
+    #[test]
+    fn should_rewrite_inline_snapshots_in_source_file() {
+        let directory = TempDirectory::new("inline");
+        let path = directory.path().join("render.rs");
+        fs::create_dir_all(directory.path()).unwrap();
+        fs::write(&path,
+                  "fn first() {\n    assert_that!(&a).matches_inline_snapshot(\"\");\n}\n\
+                   fn second() {\n    assert_that!(&b).matches_inline_snapshot(r#\"old\"#);\n}\n")
+            .unwrap();
+
+        assert_that(&update_inline_snapshot(&path, 2, 2, 22, "[\n    1,\n]")).is_ok();
+        assert_that(&update_inline_snapshot(&path, 5, 5, 22, "2")).is_ok();
+
+        assert_that(&fs::read_to_string(&path).unwrap())
+            .is_equal_to("fn first() {\n    assert_that!(&a).matches_inline_snapshot(r#\"\n        \
+                          [\n            1,\n        ]\n    \"#);\n}\n\
+                          fn second() {\n    assert_that!(&b).matches_inline_snapshot(r#\"2\"#);\
+                          \n}\n"
+                .to_string());
+    }
+}
@@ -1,151 +1,614 @@
-use super::{AssertionFailure, DescriptiveSpec, Spec};
+use super::{AssertionFailure, Spec};
 
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
+use std::ffi::{OsStr, OsString};
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+#[cfg(feature = "unicode")]
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+#[cfg(feature = "unicode")]
+use unicode_width::UnicodeWidthStr;
 
 pub trait StrAssertions {
-    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E);
-    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E);
-    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E);
-    fn is_empty(&mut self);
+    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn does_not_contain<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn is_empty(&mut self) -> &mut Self;
+    fn has_char_count(&mut self, expected: usize) -> &mut Self;
+    fn has_byte_length(&mut self, expected: usize) -> &mut Self;
+    #[cfg(feature = "unicode")]
+    fn has_max_width(&mut self, max: usize) -> &mut Self;
+    #[cfg(feature = "unicode")]
+    fn is_normalized_nfc(&mut self) -> &mut Self;
+    fn is_equal_ignoring_case<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn is_equal_ignoring_whitespace<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+    fn has_line_count(&mut self, expected: usize) -> &mut Self;
+    fn contains_line<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self;
+}
+
+#[cfg(feature = "regex")]
+pub trait RegexAssertions {
+    fn matches_regex<'r, E: Borrow<&'r str>>(&mut self, pattern: E) -> Spec<'_, Vec<String>>;
+}
+
+/// A string in the platform's native encoding, such as an `OsString` or `&OsStr`, which is not
+/// necessarily valid unicode.
+///
+/// This is what `OsStrAssertions` are implemented against.
+pub trait PlatformString {
+    fn as_platform_str(&self) -> &OsStr;
+}
+
+pub trait OsStrAssertions {
+    fn starts_with<E: AsRef<OsStr>>(&mut self, expected: E) -> &mut Self;
+    fn ends_with<E: AsRef<OsStr>>(&mut self, expected: E) -> &mut Self;
+    fn contains<E: AsRef<OsStr>>(&mut self, expected: E) -> &mut Self;
+    fn does_not_contain<E: AsRef<OsStr>>(&mut self, expected: E) -> &mut Self;
+    fn is_empty(&mut self) -> &mut Self;
 }
 
-impl<'s> StrAssertions for Spec<'s, &'s str> {
-    /// Asserts that the subject `&str` starts with the provided `&str`.
+pub trait CharAssertions {
+    fn is_alphabetic(&mut self) -> &mut Self;
+    fn is_whitespace(&mut self) -> &mut Self;
+    fn is_uppercase(&mut self) -> &mut Self;
+    fn is_lowercase(&mut self) -> &mut Self;
+}
+
+impl<'s, S> StrAssertions for Spec<'s, S>
+    where S: AsRef<str>
+{
+    /// Asserts that the subject starts with the provided `&str`. The subject type must
+    /// implement `AsRef<str>`, such as `&str`, `String`, `Cow<str>`, `Box<str>` or `Arc<str>`.
     ///
     /// ```rust,ignore
     /// assert_that(&"Hello").starts_with(&"H");
     /// ```
-    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = self.subject;
-        starts_with(self, subject, expected);
+    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject().as_ref();
+        let borrowed_expected = expected.borrow();
+
+        if !subject.starts_with(borrowed_expected) {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("starts_with")
+                .with_expected(format!("string starting with <{:?}>", borrowed_expected))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
     }
 
-    /// Asserts that the subject `&str` ends with the provided `&str`.
+    /// Asserts that the subject ends with the provided `&str`. The subject type must implement
+    /// `AsRef<str>`.
     ///
     /// ```rust,ignore
     /// assert_that(&"Hello").ends_with(&"o");
     /// ```
-    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = self.subject;
-        ends_with(self, subject, expected);
+    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject().as_ref();
+        let borrowed_expected = expected.borrow();
+
+        if !subject.ends_with(borrowed_expected) {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("ends_with")
+                .with_expected(format!("string ending with <{:?}>", borrowed_expected))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
     }
 
-    /// Asserts that the subject `&str` contains the provided `&str`.
+    /// Asserts that the subject contains the provided `&str`. The subject type must implement
+    /// `AsRef<str>`.
     ///
     /// ```rust,ignore
     /// assert_that(&"Hello").contains(&"e");
     /// ```
-    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = self.subject;
-        contains(self, subject, expected);
+    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject().as_ref();
+        let borrowed_expected = expected.borrow();
+
+        if !subject.contains(borrowed_expected) {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("contains")
+                .with_expected(format!("string containing <{:?}>", borrowed_expected))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject does not contain the provided `&str`. The subject type must
+    /// implement `AsRef<str>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello").does_not_contain(&"x");
+    /// ```
+    fn does_not_contain<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject().as_ref();
+        let borrowed_expected = expected.borrow();
+
+        if subject.contains(borrowed_expected) {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("does_not_contain")
+                .with_expected(format!("string not containing <{:?}>", borrowed_expected))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
     }
 
-    /// Asserts that the subject `&str` is empty.
+    /// Asserts that the subject is empty. The subject type must implement `AsRef<str>`.
     ///
     /// ```rust,ignore
     /// assert_that(&"").is_empty();
     /// ```
-    fn is_empty(&mut self) {
-        let subject = self.subject;
-        is_empty(self, subject);
+    fn is_empty(&mut self) -> &mut Self {
+        let subject = self.subject().as_ref();
+
+        if !subject.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("is_empty")
+                .with_expected("an empty string".to_string())
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject consists of the provided number of `char`s, that is unicode
+    /// scalar values. The subject type must implement `AsRef<str>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Grüße").has_char_count(5);
+    /// ```
+    fn has_char_count(&mut self, expected: usize) -> &mut Self {
+        let subject = self.subject().as_ref();
+        let char_count = subject.chars().count();
+
+        if char_count != expected {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("has_char_count")
+                .with_expected(format!("string with <{}> chars", expected))
+                .with_actual(format!("<{}> chars in <{:?}>", char_count, subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is the provided number of bytes long when encoded as UTF-8. The
+    /// subject type must implement `AsRef<str>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Grüße").has_byte_length(7);
+    /// ```
+    fn has_byte_length(&mut self, expected: usize) -> &mut Self {
+        let subject = self.subject().as_ref();
+
+        if subject.len() != expected {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("has_byte_length")
+                .with_expected(format!("string with <{}> bytes", expected))
+                .with_actual(format!("<{}> bytes in <{:?}>", subject.len(), subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject takes up at most the provided number of columns when displayed,
+    /// where for example most CJK characters and emoji take up two columns. The subject type
+    /// must implement `AsRef<str>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"日本").has_max_width(4);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn has_max_width(&mut self, max: usize) -> &mut Self {
+        let subject = self.subject().as_ref();
+        let width = subject.width();
+
+        if width > max {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("has_max_width")
+                .with_expected(format!("string with display width of at most <{}>", max))
+                .with_actual(format!("display width <{}> of <{:?}>", width, subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is in Unicode Normalization Form C, in which characters are
+    /// composed where possible. The subject type must implement `AsRef<str>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"caf\u{e9}").is_normalized_nfc();
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn is_normalized_nfc(&mut self) -> &mut Self {
+        let subject = self.subject().as_ref();
+
+        if !is_nfc(subject) {
+            let normalized: String = subject.nfc().collect();
+
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("is_normalized_nfc")
+                .with_expected("string in normalization form NFC".to_string())
+                .with_actual(format!("<{}>", subject.escape_unicode()))
+                .with_context(format!("normalized: <{}>", normalized.escape_unicode()))
+                .fail();
+        }
+
+        self
     }
-}
 
-impl<'s> StrAssertions for Spec<'s, String> {
-    /// Asserts that the subject `String` starts with the provided `&str`.
+    /// Asserts that the subject is equal to the provided `&str` when both are compared in
+    /// lowercase. The subject type must implement `AsRef<str>`.
     ///
     /// ```rust,ignore
-    /// assert_that(&"Hello".to_owned()).starts_with(&"H");
+    /// assert_that(&"Hello").is_equal_ignoring_case(&"HELLO");
     /// ```
-    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = &self.subject;
-        starts_with(self, subject, expected);
+    fn is_equal_ignoring_case<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject().as_ref();
+        let borrowed_expected = expected.borrow();
+
+        if subject.to_lowercase() != borrowed_expected.to_lowercase() {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("is_equal_ignoring_case")
+                .with_expected(format!("string equal to <{:?}> ignoring case", borrowed_expected))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
     }
 
-    /// Asserts that the subject `String` ends with the provided `&str`.
+    /// Asserts that the subject is equal to the provided `&str` when all whitespace is removed
+    /// from both. The subject type must implement `AsRef<str>`.
     ///
     /// ```rust,ignore
-    /// assert_that(&"Hello".to_owned()).ends_with(&"o");
+    /// assert_that(&"Hello").is_equal_ignoring_whitespace(&" Hel lo\n");
     /// ```
-    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = &self.subject;
-        ends_with(self, subject, expected);
+    fn is_equal_ignoring_whitespace<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject().as_ref();
+        let borrowed_expected = expected.borrow();
+
+        if remove_whitespace(subject) != remove_whitespace(borrowed_expected) {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("is_equal_ignoring_whitespace")
+                .with_expected(format!("string equal to <{:?}> ignoring whitespace",
+                                       borrowed_expected))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
     }
 
-    /// Asserts that the subject `String` contains the provided `&str`.
+    /// Asserts that the subject has the provided number of lines. The subject type must
+    /// implement `AsRef<str>`.
     ///
     /// ```rust,ignore
-    /// assert_that(&"Hello".to_owned()).contains(&"e");
+    /// assert_that(&"Hello").has_line_count(1);
     /// ```
-    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = &self.subject;
-        contains(self, subject, expected);
+    fn has_line_count(&mut self, expected: usize) -> &mut Self {
+        let subject = self.subject().as_ref();
+        let line_count = subject.lines().count();
+
+        if line_count != expected {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("has_line_count")
+                .with_expected(format!("string with <{}> lines", expected))
+                .with_actual(format!("<{}> lines in <{:?}>", line_count, subject))
+                .fail();
+        }
+
+        self
     }
 
-    /// Asserts that the subject `String` is empty.
+    /// Asserts that one of the lines of the subject is equal to the provided `&str`. The subject
+    /// type must implement `AsRef<str>`.
     ///
     /// ```rust,ignore
-    /// assert_that(&"".to_owned()).is_empty();
+    /// assert_that(&"Hello").contains_line(&"Hello");
     /// ```
-    fn is_empty(&mut self) {
-        let subject = &self.subject;
-        is_empty(self, subject);
+    fn contains_line<'r, E: Borrow<&'r str>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject().as_ref();
+        let borrowed_expected = expected.borrow();
+
+        if !subject.lines().any(|line| line == *borrowed_expected) {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("contains_line")
+                .with_expected(format!("string containing line <{:?}>", borrowed_expected))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
     }
 }
 
-fn starts_with<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
-                                                                   subject: &str,
-                                                                   expected: E) {
-    let borrowed_expected = expected.borrow();
+#[cfg(feature = "regex")]
+impl<'s, S> RegexAssertions for Spec<'s, S>
+    where S: AsRef<str>
+{
+    /// Asserts that the subject contains a match of the provided regular expression. Anchor the
+    /// pattern with `^` and `$` to match the whole string. The subject type must implement
+    /// `AsRef<str>`.
+    ///
+    /// This will return a new `Spec` containing the groups captured by the first match, where
+    /// groups which did not participate in the match are empty.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello").matches_regex(&"^H(e)(l+)o$").is_equal_to(vec!["e", "ll"]);
+    /// ```
+    fn matches_regex<'r, E: Borrow<&'r str>>(&mut self, pattern: E) -> Spec<'_, Vec<String>> {
+        let subject = self.subject().as_ref();
+        let borrowed_pattern = pattern.borrow();
+
+        let regex = match Regex::new(borrowed_pattern) {
+            Ok(regex) => regex,
+            Err(error) => {
+                AssertionFailure::from_spec(self)
+                    .with_assertion_name("matches_regex")
+                    .fail_fatal_with_message(format!("invalid regex <{:?}>: {}",
+                                                     borrowed_pattern,
+                                                     error))
+            }
+        };
+
+        match regex.captures(subject) {
+            Some(captures) => {
+                let groups = captures.iter()
+                    .skip(1)
+                    .map(|group| group.map_or(String::new(), |group| group.as_str().to_string()))
+                    .collect();
+
+                self.derive_owned(groups)
+            }
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_assertion_name("matches_regex")
+                    .with_expected(format!("string matching regex <{:?}>", borrowed_pattern))
+                    .with_actual(format!("<{:?}>", subject))
+                    .fail_fatal()
+            }
+        }
+    }
+}
 
-    if !subject.starts_with(borrowed_expected) {
-        AssertionFailure::from_spec(spec)
-            .with_expected(format!("string starting with <{:?}>", borrowed_expected))
-            .with_actual(format!("<{:?}>", subject))
-            .fail();
+impl PlatformString for OsString {
+    fn as_platform_str(&self) -> &OsStr {
+        self
     }
 }
 
-fn ends_with<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
-                                                                 subject: &str,
-                                                                 expected: E) {
-    let borrowed_expected = expected.borrow();
+impl PlatformString for &OsStr {
+    fn as_platform_str(&self) -> &OsStr {
+        self
+    }
+}
 
-    if !subject.ends_with(borrowed_expected) {
-        AssertionFailure::from_spec(spec)
-            .with_expected(format!("string ending with <{:?}>", borrowed_expected))
-            .with_actual(format!("<{:?}>", subject))
-            .fail();
+impl PlatformString for Box<OsStr> {
+    fn as_platform_str(&self) -> &OsStr {
+        self
     }
 }
 
-fn contains<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
-                                                                subject: &str,
-                                                                expected: E) {
-    let borrowed_expected = expected.borrow();
+impl<'a> PlatformString for Cow<'a, OsStr> {
+    fn as_platform_str(&self) -> &OsStr {
+        self
+    }
+}
+
+impl<'s, S> OsStrAssertions for Spec<'s, S>
+    where S: PlatformString
+{
+    /// Asserts that the subject starts with the provided value. The subject type must be a
+    /// `PlatformString`, such as `OsString` or `&OsStr`, and is compared without any lossy
+    /// conversion to unicode.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&OsString::from("Hello")).starts_with("H");
+    /// ```
+    fn starts_with<E: AsRef<OsStr>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject().as_platform_str();
+        let expected = expected.as_ref();
+
+        if !subject.as_encoded_bytes().starts_with(expected.as_encoded_bytes()) {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("starts_with")
+                .with_expected(format!("string starting with <{:?}>", expected))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject ends with the provided value. The subject type must be a
+    /// `PlatformString`, such as `OsString` or `&OsStr`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&OsString::from("Hello")).ends_with("o");
+    /// ```
+    fn ends_with<E: AsRef<OsStr>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject().as_platform_str();
+        let expected = expected.as_ref();
+
+        if !subject.as_encoded_bytes().ends_with(expected.as_encoded_bytes()) {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("ends_with")
+                .with_expected(format!("string ending with <{:?}>", expected))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject contains the provided value. The subject type must be a
+    /// `PlatformString`, such as `OsString` or `&OsStr`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&OsString::from("Hello")).contains("ell");
+    /// ```
+    fn contains<E: AsRef<OsStr>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject().as_platform_str();
+        let expected = expected.as_ref();
+
+        if !contains_bytes(subject.as_encoded_bytes(), expected.as_encoded_bytes()) {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("contains")
+                .with_expected(format!("string containing <{:?}>", expected))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject does not contain the provided value. The subject type must be a
+    /// `PlatformString`, such as `OsString` or `&OsStr`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&OsString::from("Hello")).does_not_contain("x");
+    /// ```
+    fn does_not_contain<E: AsRef<OsStr>>(&mut self, expected: E) -> &mut Self {
+        let subject = self.subject().as_platform_str();
+        let expected = expected.as_ref();
+
+        if contains_bytes(subject.as_encoded_bytes(), expected.as_encoded_bytes()) {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("does_not_contain")
+                .with_expected(format!("string not containing <{:?}>", expected))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
+    }
 
-    if !subject.contains(borrowed_expected) {
-        AssertionFailure::from_spec(spec)
-            .with_expected(format!("string containing <{:?}>", borrowed_expected))
-            .with_actual(format!("<{:?}>", subject))
-            .fail();
+    /// Asserts that the subject is empty. The subject type must be a `PlatformString`, such as
+    /// `OsString` or `&OsStr`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&OsString::new()).is_empty();
+    /// ```
+    fn is_empty(&mut self) -> &mut Self {
+        let subject = self.subject().as_platform_str();
+
+        if !subject.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("is_empty")
+                .with_expected("an empty string".to_string())
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+
+        self
     }
 }
 
-fn is_empty<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
-    if !subject.is_empty() {
-        AssertionFailure::from_spec(spec)
-            .with_expected(format!("an empty string"))
-            .with_actual(format!("<{:?}>", subject))
-            .fail();
+impl<'s> CharAssertions for Spec<'s, char> {
+    /// Asserts that the subject `char` is alphabetic.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&'a').is_alphabetic();
+    /// ```
+    fn is_alphabetic(&mut self) -> &mut Self {
+        let subject = *self.subject();
+
+        if !subject.is_alphabetic() {
+            fail_char_assertion(self, "is_alphabetic", "alphabetic", subject);
+        }
+
+        self
+    }
+
+    /// Asserts that the subject `char` is whitespace.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&' ').is_whitespace();
+    /// ```
+    fn is_whitespace(&mut self) -> &mut Self {
+        let subject = *self.subject();
+
+        if !subject.is_whitespace() {
+            fail_char_assertion(self, "is_whitespace", "whitespace", subject);
+        }
+
+        self
+    }
+
+    /// Asserts that the subject `char` is uppercase.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&'A').is_uppercase();
+    /// ```
+    fn is_uppercase(&mut self) -> &mut Self {
+        let subject = *self.subject();
+
+        if !subject.is_uppercase() {
+            fail_char_assertion(self, "is_uppercase", "uppercase", subject);
+        }
+
+        self
+    }
+
+    /// Asserts that the subject `char` is lowercase.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&'a').is_lowercase();
+    /// ```
+    fn is_lowercase(&mut self) -> &mut Self {
+        let subject = *self.subject();
+
+        if !subject.is_lowercase() {
+            fail_char_assertion(self, "is_lowercase", "lowercase", subject);
+        }
+
+        self
     }
 }
 
+fn remove_whitespace(value: &str) -> String {
+    value.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+fn contains_bytes(value: &[u8], expected: &[u8]) -> bool {
+    expected.is_empty() || value.windows(expected.len()).any(|window| window == expected)
+}
+
+fn fail_char_assertion(spec: &Spec<char>,
+                       assertion_name: &'static str,
+                       kind: &str,
+                       subject: char) {
+    AssertionFailure::from_spec(spec)
+        .with_assertion_name(assertion_name)
+        .with_expected(format!("{} char", kind))
+        .with_actual(format!("<{:?}>", subject))
+        .fail();
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::super::prelude::*;
 
+    use std::borrow::Cow;
+    use std::ffi::{OsStr, OsString};
+    use std::rc::Rc;
+    use std::sync::Arc;
+
     #[test]
     fn should_allow_multiple_borrow_forms_for_str() {
         let value = "Hello";
@@ -162,6 +625,19 @@ mod tests {
         assert_that(&value).contains(&"l");
     }
 
+    #[test]
+    fn should_allow_chaining_str_assertions() {
+        let value = "Hello";
+        assert_that(&value).starts_with(&"H").ends_with(&"o").contains(&"ell");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <\"x\">\n\t but was: <\"Hello\">")]
+    fn should_panic_if_chained_str_assertion_fails() {
+        let value = "Hello";
+        assert_that(&value).starts_with(&"H").contains(&"x").ends_with(&"o");
+    }
+
     #[test]
     fn should_not_panic_if_str_starts_with_value() {
         let value = "Hello";
@@ -284,4 +760,244 @@ mod tests {
         assert_that(&value).is_empty();
     }
 
+    #[test]
+    fn should_not_panic_if_str_does_not_contain_value() {
+        let value = "Hello";
+        assert_that(&value).does_not_contain(&"A");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string not containing <\"ll\">\
+                   \n\t but was: <\"Hello\">")]
+    fn should_panic_if_string_contains_value_when_not_expected() {
+        let value = "Hello".to_owned();
+        assert_that(&value).does_not_contain(&"ll");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_is_equal_ignoring_case() {
+        assert_that(&"Hello").is_equal_ignoring_case(&"hELLO");
+        assert_that(&"Hello".to_owned()).is_equal_ignoring_case(&"HELLO");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string equal to <\"Help\"> ignoring case\
+                   \n\t but was: <\"Hello\">")]
+    fn should_panic_if_str_is_not_equal_ignoring_case() {
+        assert_that(&"Hello").is_equal_ignoring_case(&"Help");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_is_equal_ignoring_whitespace() {
+        assert_that(&"Hello world").is_equal_ignoring_whitespace(&" Hello\n\tworld ");
+        assert_that(&"Hello world".to_owned()).is_equal_ignoring_whitespace(&"Helloworld");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string equal to <\"Hello, world\"> ignoring whitespace\
+                   \n\t but was: <\"Hello world\">")]
+    fn should_panic_if_str_is_not_equal_ignoring_whitespace() {
+        assert_that(&"Hello world").is_equal_ignoring_whitespace(&"Hello, world");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_has_line_count() {
+        assert_that(&"first\nsecond\r\nthird\n").has_line_count(3);
+        assert_that(&"".to_owned()).has_line_count(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with <1> lines\
+                   \n\t but was: <2> lines in <\"first\\nsecond\">")]
+    fn should_panic_if_str_does_not_have_line_count() {
+        assert_that(&"first\nsecond").has_line_count(1);
+    }
+
+    #[test]
+    fn should_not_panic_if_str_contains_line() {
+        assert_that(&"first\nsecond\r\nthird").contains_line(&"second");
+        assert_that(&"first\nsecond".to_owned()).contains_line(&"first");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing line <\"sec\">\
+                   \n\t but was: <\"first\\nsecond\">")]
+    fn should_panic_if_str_does_not_contain_line() {
+        assert_that(&"first\nsecond").contains_line(&"sec");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn should_return_captured_groups_if_str_matches_regex() {
+        assert_that(&"Hello").matches_regex(&"^H(e)(l+)o$").is_equal_to(vec!["e".to_string(),
+                                                                             "ll".to_string()]);
+        assert_that(&"version 1.2".to_owned())
+            .matches_regex(&r"(\d+)\.(\d+)(?:\.(\d+))?")
+            .element_at(2)
+            .is_empty();
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn should_be_able_to_match_several_regexes_against_same_str() {
+        let mut spec = assert_that(&"key=value");
+
+        spec.matches_regex(&r"^(\w+)=").has_length(1);
+        spec.matches_regex(&r"=(\w+)$").first().is_equal_to("value".to_string());
+        spec.starts_with(&"key");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic(expected = "\n\texpected: string matching regex <\"^\\\\d+$\">\
+                   \n\t but was: <\"Hello\">")]
+    fn should_panic_if_str_does_not_match_regex() {
+        assert_that(&"Hello").matches_regex(&r"^\d+$");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic(expected = "\n\tinvalid regex <\"(\">")]
+    fn should_panic_if_regex_is_invalid() {
+        assert_that(&"Hello").matches_regex(&"(");
+    }
+
+    #[test]
+    fn should_support_other_string_types() {
+        let owned: Cow<str> = Cow::Owned("Hello".to_string());
+        let boxed: Box<str> = "Hello".into();
+        let shared: Arc<str> = "Hello".into();
+        let counted: Rc<str> = "Hello".into();
+
+        assert_that(&Cow::Borrowed("Hello")).starts_with(&"H").ends_with(&"o");
+        assert_that(&owned).contains(&"ell");
+        assert_that(&boxed).has_line_count(1);
+        assert_that(&shared).is_equal_ignoring_case(&"hello");
+        assert_that(&counted).does_not_contain(&"x");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string ending with <\"A\">\n\t but was: <\"Hello\">")]
+    fn should_panic_if_boxed_str_does_not_end_with_value() {
+        let boxed: Box<str> = "Hello".into();
+        assert_that(&boxed).ends_with(&"A");
+    }
+
+    #[test]
+    fn should_not_panic_if_os_string_matches_expectations() {
+        let value = OsString::from("Hello");
+
+        assert_that(&value).starts_with("H").ends_with("o").contains("ell").does_not_contain("x");
+        assert_that(&value.as_os_str()).contains(OsStr::new("llo"));
+        assert_that(&OsString::new()).is_empty();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <\"x\">\n\t but was: <\"Hello\">")]
+    fn should_panic_if_os_string_does_not_contain_value() {
+        assert_that(&OsString::from("Hello")).contains("x");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn should_compare_os_string_without_lossy_conversion() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let value = OsStr::new("Hello");
+        let invalid = OsStr::from_bytes(b"He\xFFllo");
+
+        assert_that(&invalid).starts_with("He").ends_with("llo").does_not_contain("\u{FFFD}");
+        assert_that(&value).does_not_contain(OsStr::from_bytes(b"\xFF"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[should_panic(expected = "\n\texpected: an empty string\n\t but was: <\"He\\xFFllo\">")]
+    fn should_keep_invalid_unicode_in_os_string_failure() {
+        use std::os::unix::ffi::OsStrExt;
+
+        assert_that(&OsStr::from_bytes(b"He\xFFllo")).is_empty();
+    }
+
+    #[test]
+    fn should_not_panic_if_char_matches_expectations() {
+        assert_that(&'a').is_alphabetic().is_lowercase();
+        assert_that(&'Ä').is_alphabetic().is_uppercase();
+        assert_that(&'\t').is_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: alphabetic char\n\t but was: <'1'>")]
+    fn should_panic_if_char_is_not_alphabetic() {
+        assert_that(&'1').is_alphabetic();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: whitespace char\n\t but was: <'a'>")]
+    fn should_panic_if_char_is_not_whitespace() {
+        assert_that(&'a').is_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: uppercase char\n\t but was: <'a'>")]
+    fn should_panic_if_char_is_not_uppercase() {
+        assert_that(&'a').is_uppercase();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: lowercase char\n\t but was: <'A'>")]
+    fn should_panic_if_char_is_not_lowercase() {
+        assert_that(&'A').is_lowercase();
+    }
+
+    #[test]
+    fn should_not_panic_if_str_has_char_count_and_byte_length() {
+        assert_that(&"Grüße").has_char_count(5).has_byte_length(7);
+        assert_that(&"".to_owned()).has_char_count(0).has_byte_length(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with <7> chars\
+                   \n\t but was: <5> chars in <\"Grüße\">")]
+    fn should_panic_if_str_does_not_have_char_count() {
+        assert_that(&"Grüße").has_char_count(7);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with <5> bytes\
+                   \n\t but was: <7> bytes in <\"Grüße\">")]
+    fn should_panic_if_str_does_not_have_byte_length() {
+        assert_that(&"Grüße").has_byte_length(5);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn should_not_panic_if_str_fits_max_width() {
+        assert_that(&"日本").has_char_count(2).has_max_width(4);
+        assert_that(&"e\u{301}").has_max_width(1);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    #[should_panic(expected = "\n\texpected: string with display width of at most <3>\
+                   \n\t but was: display width <4> of <\"日本\">")]
+    fn should_panic_if_str_is_wider_than_max_width() {
+        assert_that(&"日本").has_max_width(3);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn should_not_panic_if_str_is_normalized_nfc() {
+        assert_that(&"caf\u{e9}").is_normalized_nfc();
+        assert_that(&"plain ascii".to_owned()).is_normalized_nfc();
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    #[should_panic(expected = "\n\texpected: string in normalization form NFC\
+                   \n\t but was: <\\u{63}\\u{61}\\u{66}\\u{65}\\u{301}>\
+                   \n\n\tnormalized: <\\u{63}\\u{61}\\u{66}\\u{e9}>")]
+    fn should_panic_if_str_is_not_normalized_nfc() {
+        assert_that(&"cafe\u{301}").is_normalized_nfc();
+    }
 }
@@ -1,42 +1,244 @@
 use super::{AssertionFailure, Spec};
 
-pub trait VecAssertions {
-    fn has_length(&mut self, expected: usize);
-    fn is_empty(&mut self);
+use std::collections::VecDeque;
+
+/// A collection with a length and indexable elements, such as a `Vec`, slice, array,
+/// `Box<[T]>` or `VecDeque`.
+///
+/// This is what `SliceAssertions` are implemented against.
+pub trait Sequence {
+    type Item;
+
+    /// The name of the collection type, used in failure messages.
+    const NAME: &'static str;
+
+    fn sequence_len(&self) -> usize;
+    fn sequence_get(&self, index: usize) -> Option<&Self::Item>;
+}
+
+pub trait SliceAssertions<'r, T> {
+    fn has_length(&mut self, expected: usize) -> &mut Self;
+    fn has_length_between(&mut self, min: usize, max: usize) -> &mut Self;
+    fn is_empty(&mut self) -> &mut Self;
+    fn first(&mut self) -> Spec<'_, T>;
+    fn last(&mut self) -> Spec<'_, T>;
+    fn element_at(&mut self, index: usize) -> Spec<'_, T>;
+}
+
+/// The assertions of `SliceAssertions`, under the name they had when they were only available for
+/// `Vec`.
+///
+/// This is implemented for everything which implements `SliceAssertions`, and both are exported
+/// by the prelude.
+pub trait VecAssertions<'r, T>: SliceAssertions<'r, T> {}
+
+impl<'r, T, A> VecAssertions<'r, T> for A where A: SliceAssertions<'r, T> + ?Sized {}
+
+impl<T> Sequence for Vec<T> {
+    type Item = T;
+    const NAME: &'static str = "vec";
+
+    fn sequence_len(&self) -> usize {
+        self.len()
+    }
+
+    fn sequence_get(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
+}
+
+impl<T> Sequence for &[T] {
+    type Item = T;
+    const NAME: &'static str = "slice";
+
+    fn sequence_len(&self) -> usize {
+        self.len()
+    }
+
+    fn sequence_get(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
 }
 
-impl<'s, T> VecAssertions for Spec<'s, Vec<T>> {
-    /// Asserts that the length of the subject vector is equal to the provided length. The subject
-    /// type must be of `Vec`.
+impl<T, const N: usize> Sequence for [T; N] {
+    type Item = T;
+    const NAME: &'static str = "array";
+
+    fn sequence_len(&self) -> usize {
+        N
+    }
+
+    fn sequence_get(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
+}
+
+impl<T> Sequence for Box<[T]> {
+    type Item = T;
+    const NAME: &'static str = "slice";
+
+    fn sequence_len(&self) -> usize {
+        self.len()
+    }
+
+    fn sequence_get(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
+}
+
+impl<T> Sequence for VecDeque<T> {
+    type Item = T;
+    const NAME: &'static str = "vec deque";
+
+    fn sequence_len(&self) -> usize {
+        self.len()
+    }
+
+    fn sequence_get(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
+}
+
+impl<'s, S> SliceAssertions<'s, S::Item> for Spec<'s, S>
+    where S: Sequence
+{
+    /// Asserts that the length of the subject is equal to the provided length. The subject type
+    /// must be a `Sequence`, such as a `Vec`, slice or array.
     ///
     /// ```rust,ignore
     /// assert_that(&vec![1, 2, 3, 4]).has_length(4);
     /// ```
-    fn has_length(&mut self, expected: usize) {
-        let length = self.subject.len();
+    fn has_length(&mut self, expected: usize) -> &mut Self {
+        let length = self.subject().sequence_len();
         if length != expected {
             AssertionFailure::from_spec(self)
-                .with_expected(format!("vec to have length <{}>", expected))
+                .with_assertion_name("has_length")
+                .with_expected(format!("{} to have length <{}>", S::NAME, expected))
                 .with_actual(format!("<{}>", length))
                 .fail();
         }
+
+        self
     }
 
-    /// Asserts that the subject vector is empty. The subject type must be of `Vec`.
+    /// Asserts that the length of the subject is between the provided lengths, inclusive. The
+    /// subject type must be a `Sequence`, such as a `Vec`, slice or array.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&[1, 2, 3]).has_length_between(2, 4);
+    /// ```
+    fn has_length_between(&mut self, min: usize, max: usize) -> &mut Self {
+        let length = self.subject().sequence_len();
+        if length < min || length > max {
+            AssertionFailure::from_spec(self)
+                .with_assertion_name("has_length_between")
+                .with_expected(format!("{} to have length between <{}> and <{}>",
+                                       S::NAME,
+                                       min,
+                                       max))
+                .with_actual(format!("<{}>", length))
+                .fail();
+        }
+
+        self
+    }
+
+    /// Asserts that the subject is empty. The subject type must be a `Sequence`, such as a
+    /// `Vec`, slice or array.
     ///
     /// ```rust,ignore
     /// let test_vec: Vec<u8> = vec![];
     /// assert_that(&test_vec).is_empty();
     /// ```
-    fn is_empty(&mut self) {
-        let subject = self.subject;
+    fn is_empty(&mut self) -> &mut Self {
+        let length = self.subject().sequence_len();
 
-        if !subject.is_empty() {
+        if length != 0 {
             AssertionFailure::from_spec(self)
-                .with_expected(format!("an empty vec"))
-                .with_actual(format!("a vec with length <{:?}>", subject.len()))
+                .with_assertion_name("is_empty")
+                .with_expected(format!("an empty {}", S::NAME))
+                .with_actual(format!("{} with length <{:?}>", with_article(S::NAME), length))
                 .fail();
         }
+
+        self
+    }
+
+    /// Asserts that the subject has a first element. The subject type must be a `Sequence`, such
+    /// as a `Vec`, slice or array.
+    ///
+    /// This will return a new `Spec` containing the first element.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3]).first().is_equal_to(&1);
+    /// ```
+    fn first(&mut self) -> Spec<'_, S::Item> {
+        match self.subject().sequence_get(0) {
+            Some(element) => self.derive(element),
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_assertion_name("first")
+                    .with_expected(format!("{} to have a first element", S::NAME))
+                    .with_actual(format!("an empty {}", S::NAME))
+                    .fail_fatal();
+            }
+        }
+    }
+
+    /// Asserts that the subject has a last element. The subject type must be a `Sequence`, such
+    /// as a `Vec`, slice or array.
+    ///
+    /// This will return a new `Spec` containing the last element.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3]).last().is_equal_to(&3);
+    /// ```
+    fn last(&mut self) -> Spec<'_, S::Item> {
+        let subject = self.subject();
+        let length = subject.sequence_len();
+
+        match length.checked_sub(1).and_then(|index| subject.sequence_get(index)) {
+            Some(element) => self.derive(element),
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_assertion_name("last")
+                    .with_expected(format!("{} to have a last element", S::NAME))
+                    .with_actual(format!("an empty {}", S::NAME))
+                    .fail_fatal();
+            }
+        }
+    }
+
+    /// Asserts that the subject has an element at the provided index. The subject type must be a
+    /// `Sequence`, such as a `Vec`, slice or array.
+    ///
+    /// This will return a new `Spec` containing the element.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3]).element_at(1).is_equal_to(&2);
+    /// ```
+    fn element_at(&mut self, index: usize) -> Spec<'_, S::Item> {
+        let subject = self.subject();
+
+        match subject.sequence_get(index) {
+            Some(element) => self.derive(element),
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_assertion_name("element_at")
+                    .with_expected(format!("{} to have an element at index <{}>", S::NAME, index))
+                    .with_actual(format!("{} with length <{}>",
+                                         with_article(S::NAME),
+                                         subject.sequence_len()))
+                    .fail_fatal();
+            }
+        }
+    }
+}
+
+fn with_article(name: &str) -> String {
+    match name.chars().next() {
+        Some('a') | Some('e') | Some('i') | Some('o') | Some('u') => format!("an {}", name),
+        _ => format!("a {}", name),
     }
 }
 
@@ -45,6 +247,24 @@ mod tests {
 
     use super::super::prelude::*;
 
+    use std::collections::VecDeque;
+
+    #[test]
+    fn should_allow_chaining_vec_assertions() {
+        let test_vec = vec![3, 1, 2];
+        assert_that(&test_vec).has_length(3).contains(&1).does_not_contain(&4);
+    }
+
+    fn assert_has_length<'s, A: VecAssertions<'s, u8>>(spec: &mut A, expected: usize) {
+        spec.has_length(expected);
+    }
+
+    #[test]
+    fn should_implement_vec_assertions_for_slice_assertions() {
+        assert_has_length(&mut assert_that(&vec![1u8, 2]), 2);
+        assert_has_length(&mut assert_that(&[1u8, 2, 3]), 3);
+    }
+
     #[test]
     fn should_not_panic_if_vec_length_matches_expected() {
         let test_vec = vec![1, 2, 3];
@@ -71,4 +291,80 @@ mod tests {
         assert_that(&vec![1]).is_empty();
     }
 
+    #[test]
+    fn should_not_panic_if_slice_like_length_matches_expected() {
+        let test_slice: &[u8] = &[1, 2, 3];
+        let test_boxed: Box<[u8]> = vec![1, 2].into_boxed_slice();
+        let test_deque: VecDeque<u8> = vec![1].into_iter().collect();
+
+        assert_that(&test_slice).has_length(3);
+        assert_that(&[1, 2, 3, 4]).has_length(4);
+        assert_that(&test_boxed).has_length(2);
+        assert_that(&test_deque).has_length(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: array to have length <1>\n\t but was: <3>")]
+    fn should_panic_if_array_length_does_not_match_expected() {
+        assert_that(&[1, 2, 3]).has_length(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: an empty slice\n\t but was: a slice with length <1>")]
+    fn should_panic_if_slice_was_expected_to_be_empty_and_is_not() {
+        let test_slice: &[u8] = &[1];
+        assert_that(&test_slice).is_empty();
+    }
+
+    #[test]
+    fn should_not_panic_if_length_is_between_expected() {
+        assert_that(&vec![1, 2, 3]).has_length_between(1, 3);
+        assert_that(&vec![1, 2, 3]).has_length_between(3, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec to have length between <4> and <5>\
+                   \n\t but was: <3>")]
+    fn should_panic_if_length_is_not_between_expected() {
+        assert_that(&vec![1, 2, 3]).has_length_between(4, 5);
+    }
+
+    #[test]
+    fn should_return_first_last_and_indexed_elements() {
+        let test_deque: VecDeque<u8> = vec![1, 2, 3].into_iter().collect();
+
+        assert_that(&vec![1, 2, 3]).first().is_equal_to(1);
+        assert_that(&[1, 2, 3]).last().is_equal_to(3);
+        assert_that(&test_deque).element_at(1).is_equal_to(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec to have a first element\
+                   \n\t but was: an empty vec")]
+    fn should_panic_if_vec_has_no_first_element() {
+        let test_vec: Vec<u8> = vec![];
+        assert_that(&test_vec).first();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec deque to have a last element\
+                   \n\t but was: an empty vec deque")]
+    fn should_panic_if_vec_deque_has_no_last_element() {
+        let test_deque: VecDeque<u8> = VecDeque::new();
+        assert_that(&test_deque).last();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: array to have an element at index <3>\
+                   \n\t but was: an array with length <3>")]
+    fn should_panic_if_element_is_out_of_bounds() {
+        assert_that(&[1, 2, 3]).element_at(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [numbers]\n\texpected: <4>\n\t but was: <2>")]
+    fn should_keep_subject_name_for_element_spec() {
+        assert_that(&vec![1, 2, 3]).named("numbers").element_at(1).is_equal_to(4);
+    }
+
 }